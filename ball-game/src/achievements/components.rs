@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct Toast {
+    pub timer: Timer,
+}
+
+#[derive(Component)]
+pub struct AchievementsMenu;
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AchievementId {
+    FirstStar,
+    Centurion,
+    Untouched,
+    StarRush,
+    Pinball,
+    Marathon,
+    Veteran,
+}
+
+/// `name` and `description` are string table keys.
+pub struct Achievement {
+    pub id: AchievementId,
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+pub const ACHIEVEMENTS: [Achievement; 7] = [
+    Achievement {
+        id: AchievementId::FirstStar,
+        name: "achievement.first_star.name",
+        description: "achievement.first_star.description",
+    },
+    Achievement {
+        id: AchievementId::Centurion,
+        name: "achievement.centurion.name",
+        description: "achievement.centurion.description",
+    },
+    Achievement {
+        id: AchievementId::Untouched,
+        name: "achievement.untouched.name",
+        description: "achievement.untouched.description",
+    },
+    Achievement {
+        id: AchievementId::StarRush,
+        name: "achievement.star_rush.name",
+        description: "achievement.star_rush.description",
+    },
+    Achievement {
+        id: AchievementId::Pinball,
+        name: "achievement.pinball.name",
+        description: "achievement.pinball.description",
+    },
+    Achievement {
+        id: AchievementId::Marathon,
+        name: "achievement.marathon.name",
+        description: "achievement.marathon.description",
+    },
+    Achievement {
+        id: AchievementId::Veteran,
+        name: "achievement.veteran.name",
+        description: "achievement.veteran.description",
+    },
+];
+
+pub fn achievement(id: AchievementId) -> &'static Achievement {
+    ACHIEVEMENTS
+        .iter()
+        .find(|achievement| achievement.id == id)
+        .unwrap()
+}
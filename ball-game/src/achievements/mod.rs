@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use systems::layout::*;
+use systems::toast::*;
+use systems::tracking::*;
+
+use crate::{
+    events::AchievementUnlocked,
+    game::{level::resources::PlaytestLevel, SimulationState},
+    theme::resources::UiTheme,
+    AppState,
+};
+
+use self::resources::{Achievements, RunTracker};
+
+mod components;
+pub mod definitions;
+pub mod resources;
+mod systems;
+mod unlocker;
+
+const ACHIEVEMENTS_FILE: &str = "achievements.ron";
+
+const CENTURION_SCORE: u32 = 100;
+const UNTOUCHED_SECONDS: f32 = 120.0;
+const STAR_RUSH_STARS: usize = 10;
+/// Seconds the star rush stars have to be collected within.
+const STAR_RUSH_WINDOW: f32 = 5.0;
+const PINBALL_BOUNCES: u32 = 500;
+const MARATHON_SECONDS: f32 = 300.0;
+const VETERAN_RUNS: u32 = 10;
+
+const TOAST_SECONDS: f32 = 3.0;
+
+pub struct AchievementsPlugin;
+
+impl Plugin for AchievementsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<AchievementUnlocked>()
+            .init_resource::<Achievements>()
+            .add_systems(OnEnter(AppState::InGame), reset_run_tracker)
+            // Test plays from the editor don't count.
+            .add_systems(
+                Update,
+                (
+                    track_survival.run_if(in_state(SimulationState::Running)),
+                    track_stars,
+                    track_bounces,
+                    track_score,
+                )
+                    .run_if(in_state(AppState::InGame))
+                    .run_if(resource_exists::<RunTracker>())
+                    .run_if(not(resource_exists::<PlaytestLevel>())),
+            )
+            // The game is already over by the time this sees the event.
+            .add_systems(
+                Update,
+                track_finished_runs.run_if(not(resource_exists::<PlaytestLevel>())),
+            )
+            .add_systems(
+                Update,
+                (spawn_toasts, expire_toasts).run_if(resource_exists::<UiTheme>()),
+            )
+            .add_systems(OnEnter(AppState::Achievements), spawn_achievements_menu)
+            .add_systems(OnExit(AppState::Achievements), despawn_achievements_menu);
+    }
+}
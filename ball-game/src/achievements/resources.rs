@@ -0,0 +1,41 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{definitions::AchievementId, ACHIEVEMENTS_FILE};
+use crate::{
+    profile::resources::ActiveProfile,
+    save::files::{read_ron, write_ron},
+};
+
+/// Unlocked achievements and the counters that carry over between runs.
+#[derive(Resource, Default, Serialize, Deserialize)]
+pub struct Achievements {
+    pub unlocked: Vec<AchievementId>,
+    pub runs_finished: u32,
+}
+
+impl Achievements {
+    pub fn load(profile: &ActiveProfile) -> Self {
+        read_ron(&profile.path(ACHIEVEMENTS_FILE)).unwrap_or_default()
+    }
+
+    pub fn save(&self, profile: &ActiveProfile) {
+        write_ron(&profile.path(ACHIEVEMENTS_FILE), self);
+    }
+
+    pub fn is_unlocked(&self, id: AchievementId) -> bool {
+        self.unlocked.contains(&id)
+    }
+}
+
+/// What happened so far in the current run, as far as achievements care.
+#[derive(Resource, Default)]
+pub struct RunTracker {
+    pub elapsed: f32,
+    pub stars: u32,
+    pub bounces: u32,
+    /// When the stars collected within the star rush window were picked up.
+    pub recent_stars: VecDeque<f32>,
+}
@@ -0,0 +1,110 @@
+use bevy::prelude::*;
+
+use crate::{
+    achievements::{components::*, definitions::ACHIEVEMENTS, resources::Achievements},
+    localization::{components::LocalizedText, resources::LocalizedString},
+    main_menu::styles::{
+        get_button_text_style, get_detail_text_style, get_main_menu_style, get_title_text_style,
+    },
+    theme::{
+        components::{MenuBackground, ThemedText},
+        resources::UiTheme,
+    },
+    widgets::{builder::MenuButtonBuilder, components::MenuAction},
+    AppState,
+};
+
+pub fn spawn_achievements_menu(
+    mut commands: Commands,
+    ui_theme: Res<UiTheme>,
+    achievements: Res<Achievements>,
+) {
+    build_achievements_menu(&mut commands, &ui_theme, &achievements);
+}
+
+pub fn despawn_achievements_menu(
+    mut commands: Commands,
+    achievements_menu_query: Query<Entity, With<AchievementsMenu>>,
+) {
+    if let Ok(achievements_menu_entity) = achievements_menu_query.get_single() {
+        commands
+            .entity(achievements_menu_entity)
+            .despawn_recursive();
+    }
+}
+
+pub fn build_achievements_menu(
+    commands: &mut Commands,
+    ui_theme: &UiTheme,
+    achievements: &Achievements,
+) -> Entity {
+    let achievements_menu_entity = commands
+        .spawn((
+            NodeBundle {
+                style: get_main_menu_style(ui_theme),
+                background_color: ui_theme.palette.background.into(),
+                ..default()
+            },
+            MenuBackground {},
+            AchievementsMenu {},
+        ))
+        .with_children(|parent| {
+            // === Title ===
+            parent.spawn((
+                TextBundle::from_section("", get_title_text_style(ui_theme)),
+                LocalizedText::new(LocalizedString::new("achievements.title")),
+                ThemedText::Title,
+            ));
+            parent.spawn((
+                TextBundle::from_section("", get_detail_text_style(ui_theme)),
+                LocalizedText::new(
+                    LocalizedString::new("achievements.unlocked")
+                        .with_arg("unlocked", achievements.unlocked.len())
+                        .with_arg("total", ACHIEVEMENTS.len()),
+                ),
+                ThemedText::Detail,
+            ));
+            // === Achievement List ===
+            for achievement in ACHIEVEMENTS.iter() {
+                let (mark, color) = if achievements.is_unlocked(achievement.id) {
+                    ("[x]", ui_theme.palette.text)
+                } else {
+                    ("[ ]", ui_theme.palette.text_muted)
+                };
+                let name_style = TextStyle {
+                    color,
+                    ..get_button_text_style(ui_theme)
+                };
+                let description_style = TextStyle {
+                    color,
+                    ..get_detail_text_style(ui_theme)
+                };
+                parent.spawn((
+                    TextBundle::from_sections([
+                        TextSection::new("", name_style.clone()),
+                        TextSection::new("", name_style),
+                        TextSection::new("", description_style.clone()),
+                        TextSection::new("", description_style),
+                    ]),
+                    LocalizedText {
+                        sections: vec![
+                            LocalizedString::raw(format!("{mark} ")),
+                            LocalizedString::new(achievement.name),
+                            LocalizedString::raw("  "),
+                            LocalizedString::new(achievement.description),
+                        ],
+                    },
+                ));
+            }
+            // === Back Button ===
+            MenuButtonBuilder::new(
+                LocalizedString::new("common.back"),
+                MenuAction::GoTo(AppState::Profile),
+                0,
+            )
+            .spawn(parent, ui_theme);
+        })
+        .id();
+
+    achievements_menu_entity
+}
@@ -0,0 +1,76 @@
+use bevy::prelude::*;
+
+use crate::{
+    achievements::{components::Toast, definitions::achievement, TOAST_SECONDS},
+    events::AchievementUnlocked,
+    localization::{components::LocalizedText, resources::LocalizedString},
+    theme::resources::UiTheme,
+};
+
+fn get_toast_style(index: usize) -> Style {
+    Style {
+        position_type: PositionType::Absolute,
+        right: Val::Px(16.0),
+        top: Val::Px(16.0 + 64.0 * index as f32),
+        flex_direction: FlexDirection::Column,
+        padding: UiRect::all(Val::Px(8.0)),
+        ..default()
+    }
+}
+
+fn get_toast_text_style(ui_theme: &UiTheme, font_size: f32) -> TextStyle {
+    TextStyle {
+        font: ui_theme.font.clone(),
+        font_size,
+        color: ui_theme.palette.text,
+    }
+}
+
+/// Stacks below any toasts still showing.
+pub fn spawn_toasts(
+    mut commands: Commands,
+    mut achievement_event_reader: EventReader<AchievementUnlocked>,
+    toast_query: Query<(), With<Toast>>,
+    ui_theme: Res<UiTheme>,
+) {
+    let shown = toast_query.iter().count();
+    for (index, event) in (shown..).zip(achievement_event_reader.iter()) {
+        let achievement = achievement(event.achievement);
+        commands
+            .spawn((
+                NodeBundle {
+                    style: get_toast_style(index),
+                    background_color: ui_theme.palette.panel.into(),
+                    z_index: ZIndex::Global(10),
+                    ..default()
+                },
+                Toast {
+                    timer: Timer::from_seconds(TOAST_SECONDS, TimerMode::Once),
+                },
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    TextBundle::from_section("", get_toast_text_style(&ui_theme, 16.0)),
+                    LocalizedText::new(LocalizedString::new("achievements.toast")),
+                ));
+                parent.spawn((
+                    TextBundle::from_section("", get_toast_text_style(&ui_theme, 24.0)),
+                    LocalizedText::new(LocalizedString::new(achievement.name)),
+                ));
+            });
+    }
+}
+
+/// Uses real time, so hit stops don't hold toasts up.
+pub fn expire_toasts(
+    mut commands: Commands,
+    mut toast_query: Query<(Entity, &mut Toast)>,
+    time: Res<Time>,
+) {
+    for (toast_entity, mut toast) in toast_query.iter_mut() {
+        toast.timer.tick(time.raw_delta());
+        if toast.timer.finished() {
+            commands.entity(toast_entity).despawn_recursive();
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+
+use crate::{
+    achievements::{
+        definitions::AchievementId, resources::RunTracker, unlocker::Unlocker, CENTURION_SCORE,
+        MARATHON_SECONDS, PINBALL_BOUNCES, STAR_RUSH_STARS, STAR_RUSH_WINDOW, UNTOUCHED_SECONDS,
+        VETERAN_RUNS,
+    },
+    events::{EnemyBounced, GameOver, StarCollected},
+    game::score::resources::Score,
+};
+
+pub fn reset_run_tracker(mut commands: Commands) {
+    commands.insert_resource(RunTracker::default());
+}
+
+pub fn track_survival(
+    mut run_tracker: ResMut<RunTracker>,
+    mut unlocker: Unlocker,
+    time: Res<Time>,
+) {
+    run_tracker.elapsed += time.delta_seconds();
+
+    if run_tracker.elapsed >= UNTOUCHED_SECONDS && run_tracker.stars == 0 {
+        unlocker.unlock(AchievementId::Untouched);
+    }
+    if run_tracker.elapsed >= MARATHON_SECONDS {
+        unlocker.unlock(AchievementId::Marathon);
+    }
+}
+
+pub fn track_stars(
+    mut star_collected_event_reader: EventReader<StarCollected>,
+    mut run_tracker: ResMut<RunTracker>,
+    mut unlocker: Unlocker,
+) {
+    for _ in star_collected_event_reader.iter() {
+        let now = run_tracker.elapsed;
+        run_tracker.stars += 1;
+        run_tracker.recent_stars.push_back(now);
+        while run_tracker
+            .recent_stars
+            .front()
+            .is_some_and(|&collected| now - collected > STAR_RUSH_WINDOW)
+        {
+            run_tracker.recent_stars.pop_front();
+        }
+
+        unlocker.unlock(AchievementId::FirstStar);
+        if run_tracker.recent_stars.len() >= STAR_RUSH_STARS {
+            unlocker.unlock(AchievementId::StarRush);
+        }
+    }
+}
+
+pub fn track_bounces(
+    mut enemy_bounced_event_reader: EventReader<EnemyBounced>,
+    mut run_tracker: ResMut<RunTracker>,
+    mut unlocker: Unlocker,
+) {
+    for _ in enemy_bounced_event_reader.iter() {
+        run_tracker.bounces += 1;
+        if run_tracker.bounces >= PINBALL_BOUNCES {
+            unlocker.unlock(AchievementId::Pinball);
+        }
+    }
+}
+
+pub fn track_score(score: Res<Score>, mut unlocker: Unlocker) {
+    if score.is_changed() && score.value >= CENTURION_SCORE {
+        unlocker.unlock(AchievementId::Centurion);
+    }
+}
+
+pub fn track_finished_runs(
+    mut game_over_event_reader: EventReader<GameOver>,
+    mut unlocker: Unlocker,
+) {
+    for _ in game_over_event_reader.iter() {
+        let achievements = unlocker.achievements();
+        achievements.runs_finished += 1;
+        if achievements.runs_finished >= VETERAN_RUNS {
+            unlocker.unlock(AchievementId::Veteran);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use super::{definitions::AchievementId, resources::Achievements};
+use crate::events::AchievementUnlocked;
+
+#[derive(SystemParam)]
+pub struct Unlocker<'w> {
+    achievements: ResMut<'w, Achievements>,
+    achievement_event_writer: EventWriter<'w, AchievementUnlocked>,
+}
+
+impl<'w> Unlocker<'w> {
+    pub fn unlock(&mut self, id: AchievementId) {
+        if self.achievements.is_unlocked(id) {
+            return;
+        }
+        self.achievements.unlocked.push(id);
+        self.achievement_event_writer
+            .send(AchievementUnlocked { achievement: id });
+    }
+
+    pub fn achievements(&mut self) -> &mut Achievements {
+        &mut self.achievements
+    }
+}
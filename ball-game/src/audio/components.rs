@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MusicTrack {
+    Menu,
+    Gameplay,
+    GameOver,
+}
+
+impl MusicTrack {
+    /// The game over stinger plays once, every other track loops.
+    pub fn looping(&self) -> bool {
+        !matches!(self, MusicTrack::GameOver)
+    }
+}
+
+#[derive(Component)]
+pub struct Music {
+    pub track: MusicTrack,
+    /// Current fade level, multiplied by the music channel volume.
+    pub volume: f32,
+    /// Fade level the track is moving towards; the track is despawned once it fades out.
+    pub target_volume: f32,
+}
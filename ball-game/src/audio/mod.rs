@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+
+use crate::{events::SoundEvent, AppState};
+
+use self::{
+    music::*,
+    resources::{AudioSettings, SoundThrottle},
+    systems::*,
+};
+
+pub mod components;
+mod music;
+pub mod resources;
+mod systems;
+
+/// Same sound played again within this window (in seconds) is dropped.
+const THROTTLE_WINDOW: f32 = 0.05;
+/// Same sound played again within this window (in seconds) counts as a repeat.
+const REPEAT_WINDOW: f32 = 0.5;
+const PITCH_STEP: f32 = 0.05;
+const MAX_PITCH_STEPS: u32 = 6;
+const PITCH_JITTER: f32 = 0.04;
+/// Time (in seconds) for a music track to fade fully in or out.
+const CROSSFADE_TIME: f32 = 1.5;
+/// Gameplay music level while the simulation is paused.
+const DUCKED_MUSIC_VOLUME: f32 = 0.3;
+
+pub struct SoundPlugin;
+
+impl Plugin for SoundPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SoundEvent>()
+            .init_resource::<AudioSettings>()
+            .init_resource::<SoundThrottle>()
+            .add_systems(Update, play_sound_events)
+            .add_systems(OnEnter(AppState::MainMenu), play_menu_music)
+            .add_systems(OnEnter(AppState::InGame), play_gameplay_music)
+            .add_systems(OnEnter(AppState::GameOver), play_game_over_music)
+            .add_systems(
+                Update,
+                (
+                    duck_paused_music.run_if(in_state(AppState::InGame)),
+                    fade_music,
+                )
+                    .chain(),
+            );
+    }
+}
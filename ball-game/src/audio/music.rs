@@ -0,0 +1,131 @@
+use bevy::{
+    audio::{AudioSinkPlayback, Volume},
+    prelude::*,
+};
+
+use super::{
+    components::{Music, MusicTrack},
+    resources::{AudioSettings, SoundChannel},
+    CROSSFADE_TIME, DUCKED_MUSIC_VOLUME,
+};
+use crate::{game::SimulationState, loading::resources::GameAssets};
+
+/// Fades out every playing track other than `track` and starts `track` if it isn't playing yet.
+fn crossfade_to(
+    commands: &mut Commands,
+    music_query: &mut Query<&mut Music>,
+    game_assets: &Res<GameAssets>,
+    track: MusicTrack,
+) {
+    let mut already_playing = false;
+    for mut music in music_query.iter_mut() {
+        if music.track == track {
+            music.target_volume = 1.0;
+            already_playing = true;
+        } else {
+            music.target_volume = 0.0;
+        }
+    }
+
+    if !already_playing {
+        let settings = if track.looping() {
+            PlaybackSettings::LOOP
+        } else {
+            PlaybackSettings::ONCE
+        };
+        commands.spawn((
+            AudioBundle {
+                source: game_assets.music(track),
+                settings: settings.with_volume(Volume::new_relative(0.0)),
+            },
+            Music {
+                track,
+                volume: 0.0,
+                target_volume: 1.0,
+            },
+        ));
+    }
+}
+
+pub fn play_menu_music(
+    mut commands: Commands,
+    mut music_query: Query<&mut Music>,
+    game_assets: Res<GameAssets>,
+) {
+    crossfade_to(
+        &mut commands,
+        &mut music_query,
+        &game_assets,
+        MusicTrack::Menu,
+    );
+}
+
+pub fn play_gameplay_music(
+    mut commands: Commands,
+    mut music_query: Query<&mut Music>,
+    game_assets: Res<GameAssets>,
+) {
+    crossfade_to(
+        &mut commands,
+        &mut music_query,
+        &game_assets,
+        MusicTrack::Gameplay,
+    );
+}
+
+pub fn play_game_over_music(
+    mut commands: Commands,
+    mut music_query: Query<&mut Music>,
+    game_assets: Res<GameAssets>,
+) {
+    crossfade_to(
+        &mut commands,
+        &mut music_query,
+        &game_assets,
+        MusicTrack::GameOver,
+    );
+}
+
+pub fn duck_paused_music(
+    mut music_query: Query<&mut Music>,
+    simulation_state: Res<State<SimulationState>>,
+) {
+    let target_volume = match simulation_state.get() {
+        SimulationState::Running => 1.0,
+        SimulationState::Paused => DUCKED_MUSIC_VOLUME,
+    };
+    for mut music in music_query.iter_mut() {
+        if music.track == MusicTrack::Gameplay && music.target_volume > 0.0 {
+            music.target_volume = target_volume;
+        }
+    }
+}
+
+pub fn fade_music(
+    mut commands: Commands,
+    mut music_query: Query<(Entity, &mut Music, Option<&AudioSink>)>,
+    audio_settings: Res<AudioSettings>,
+    time: Res<Time>,
+) {
+    let step = time.raw_delta_seconds() / CROSSFADE_TIME;
+    let channel_volume = audio_settings.channel_volume(SoundChannel::Music);
+
+    for (music_entity, mut music, audio_sink) in music_query.iter_mut() {
+        if music.volume < music.target_volume {
+            music.volume = (music.volume + step).min(music.target_volume);
+        } else if music.volume > music.target_volume {
+            music.volume = (music.volume - step).max(music.target_volume);
+        }
+
+        let Some(audio_sink) = audio_sink else {
+            continue;
+        };
+
+        if (music.target_volume <= 0.0 && music.volume <= 0.0) || audio_sink.empty() {
+            commands.entity(music_entity).despawn();
+            continue;
+        }
+
+        audio_sink.set_volume(music.volume * channel_volume);
+    }
+}
@@ -0,0 +1,43 @@
+use bevy::{prelude::*, utils::HashMap};
+use serde::{Deserialize, Serialize};
+
+use crate::events::SoundEvent;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoundChannel {
+    Sfx,
+    Music,
+}
+
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct AudioSettings {
+    pub master_volume: f32,
+    pub sfx_volume: f32,
+    pub music_volume: f32,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        AudioSettings {
+            master_volume: 1.0,
+            sfx_volume: 0.8,
+            music_volume: 0.6,
+        }
+    }
+}
+
+impl AudioSettings {
+    pub fn channel_volume(&self, channel: SoundChannel) -> f32 {
+        let channel_volume = match channel {
+            SoundChannel::Sfx => self.sfx_volume,
+            SoundChannel::Music => self.music_volume,
+        };
+        (self.master_volume * channel_volume).clamp(0.0, 1.0)
+    }
+}
+
+/// When each sound was last played and how many times in a row it was repeated.
+#[derive(Resource, Default)]
+pub struct SoundThrottle {
+    pub last_played: HashMap<SoundEvent, (f32, u32)>,
+}
@@ -0,0 +1,57 @@
+use bevy::{audio::Volume, prelude::*};
+use rand::random;
+
+use super::{
+    resources::{AudioSettings, SoundChannel, SoundThrottle},
+    MAX_PITCH_STEPS, PITCH_JITTER, PITCH_STEP, REPEAT_WINDOW, THROTTLE_WINDOW,
+};
+use crate::{events::SoundEvent, loading::resources::GameAssets};
+
+fn sound_source(sound: SoundEvent, game_assets: &GameAssets) -> Handle<AudioSource> {
+    match sound {
+        SoundEvent::EnemyBounce => {
+            if random::<f32>() > 0.5 {
+                game_assets.pluck_sounds[0].clone()
+            } else {
+                game_assets.pluck_sounds[1].clone()
+            }
+        }
+        SoundEvent::StarCollected => game_assets.star_sound.clone(),
+        SoundEvent::PlayerDeath | SoundEvent::BossHit => game_assets.explosion_sound.clone(),
+    }
+}
+
+pub fn play_sound_events(
+    mut commands: Commands,
+    mut sound_event_reader: EventReader<SoundEvent>,
+    mut sound_throttle: ResMut<SoundThrottle>,
+    audio_settings: Res<AudioSettings>,
+    game_assets: Res<GameAssets>,
+    time: Res<Time>,
+) {
+    let now = time.raw_elapsed_seconds();
+    let volume = audio_settings.channel_volume(SoundChannel::Sfx);
+
+    for sound in sound_event_reader.iter() {
+        let repeats = match sound_throttle.last_played.get(sound) {
+            Some((last_played, _)) if now - last_played < THROTTLE_WINDOW => continue,
+            Some((last_played, repeats)) if now - last_played < REPEAT_WINDOW => repeats + 1,
+            _ => 0,
+        };
+        sound_throttle.last_played.insert(*sound, (now, repeats));
+
+        if volume <= 0.0 {
+            continue;
+        }
+
+        let jitter = (random::<f32>() * 2.0 - 1.0) * PITCH_JITTER;
+        let pitch = 1.0 + repeats.min(MAX_PITCH_STEPS) as f32 * PITCH_STEP + jitter;
+
+        commands.spawn(AudioBundle {
+            source: sound_source(*sound, &game_assets),
+            settings: PlaybackSettings::DESPAWN
+                .with_volume(Volume::new_relative(volume))
+                .with_speed(pitch),
+        });
+    }
+}
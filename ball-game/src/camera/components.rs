@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct GameCamera {
+    /// Resting position the shake offset is applied around.
+    pub base_translation: Vec3,
+    /// Shake intensity in `[0, 1]`; the applied shake grows with its square.
+    pub trauma: f32,
+    /// Current zoom-in amount, decays back to zero.
+    pub zoom_punch: f32,
+}
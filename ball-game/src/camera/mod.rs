@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+use crate::{events::CameraEvent, AppState};
+
+use self::{
+    resources::{CameraSettings, HitStop},
+    systems::*,
+};
+
+pub mod components;
+pub mod resources;
+mod systems;
+
+/// Trauma lost per second.
+const TRAUMA_DECAY: f32 = 1.5;
+const MAX_SHAKE_OFFSET: f32 = 24.0;
+/// Maximum shake rotation, in radians.
+const MAX_SHAKE_ANGLE: f32 = 0.05;
+const SHAKE_FREQUENCY: f32 = 40.0;
+/// Zoom punch lost per second.
+const ZOOM_PUNCH_DECAY: f32 = 0.4;
+/// Game speed while a hit-stop is active.
+const HIT_STOP_SPEED: f32 = 0.05;
+
+pub struct CameraPlugin;
+
+impl Plugin for CameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<CameraEvent>()
+            .init_resource::<CameraSettings>()
+            .init_resource::<HitStop>()
+            .add_systems(Startup, spawn_camera)
+            .add_systems(
+                Update,
+                (handle_camera_events, tick_hit_stop, apply_camera_effects).chain(),
+            )
+            .add_systems(OnEnter(AppState::InGame), reset_camera_effects)
+            .add_systems(OnExit(AppState::InGame), clear_hit_stop);
+    }
+}
@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct CameraSettings {
+    /// Accessibility option disabling screen shake, hit-stop and zoom punches.
+    pub reduced_motion: bool,
+    pub zoom_punch: bool,
+}
+
+impl Default for CameraSettings {
+    fn default() -> Self {
+        CameraSettings {
+            reduced_motion: false,
+            zoom_punch: true,
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct HitStop {
+    pub timer: Option<Timer>,
+}
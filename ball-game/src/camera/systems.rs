@@ -0,0 +1,117 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use super::{
+    components::GameCamera,
+    resources::{CameraSettings, HitStop},
+    HIT_STOP_SPEED, MAX_SHAKE_ANGLE, MAX_SHAKE_OFFSET, SHAKE_FREQUENCY, TRAUMA_DECAY,
+    ZOOM_PUNCH_DECAY,
+};
+use crate::events::CameraEvent;
+
+pub fn spawn_camera(mut commands: Commands, window_query: Query<&Window, With<PrimaryWindow>>) {
+    let window = window_query.get_single().unwrap();
+    let base_translation = Vec3::new(window.width() / 2.0, window.height() / 2.0, 1.0);
+
+    commands.spawn((
+        Camera2dBundle {
+            transform: Transform::from_translation(base_translation),
+            ..default()
+        },
+        GameCamera {
+            base_translation,
+            trauma: 0.0,
+            zoom_punch: 0.0,
+        },
+    ));
+}
+
+pub fn handle_camera_events(
+    mut camera_event_reader: EventReader<CameraEvent>,
+    mut camera_query: Query<&mut GameCamera>,
+    mut hit_stop: ResMut<HitStop>,
+    mut time: ResMut<Time>,
+    camera_settings: Res<CameraSettings>,
+) {
+    if camera_settings.reduced_motion {
+        camera_event_reader.clear();
+        return;
+    }
+
+    let Ok(mut camera) = camera_query.get_single_mut() else {
+        return;
+    };
+
+    for event in camera_event_reader.iter() {
+        match *event {
+            CameraEvent::Shake(trauma) => {
+                camera.trauma = (camera.trauma + trauma).min(1.0);
+            }
+            CameraEvent::HitStop(duration) => {
+                hit_stop.timer = Some(Timer::from_seconds(duration, TimerMode::Once));
+                time.set_relative_speed(HIT_STOP_SPEED);
+            }
+            CameraEvent::ZoomPunch(amount) => {
+                if camera_settings.zoom_punch {
+                    camera.zoom_punch = camera.zoom_punch.max(amount);
+                }
+            }
+        }
+    }
+}
+
+pub fn apply_camera_effects(
+    mut camera_query: Query<(&mut Transform, &mut OrthographicProjection, &mut GameCamera)>,
+    time: Res<Time>,
+) {
+    let Ok((mut transform, mut projection, mut camera)) = camera_query.get_single_mut() else {
+        return;
+    };
+
+    // Effects keep animating in real time during hit-stop.
+    let delta = time.raw_delta_seconds();
+    let t = time.raw_elapsed_seconds() * SHAKE_FREQUENCY;
+
+    camera.trauma = (camera.trauma - TRAUMA_DECAY * delta).max(0.0);
+    camera.zoom_punch = (camera.zoom_punch - ZOOM_PUNCH_DECAY * delta).max(0.0);
+
+    // Sum of incommensurate sines gives a smooth, noise-like wobble.
+    let shake = camera.trauma * camera.trauma;
+    let offset = Vec2::new(
+        (t * 1.0).sin() + (t * 2.3 + 1.7).sin() * 0.5,
+        (t * 1.3 + 4.1).sin() + (t * 2.9 + 0.3).sin() * 0.5,
+    ) / 1.5
+        * MAX_SHAKE_OFFSET
+        * shake;
+    let angle = ((t * 1.7 + 2.9).sin() * MAX_SHAKE_ANGLE) * shake;
+
+    transform.translation = camera.base_translation + offset.extend(0.0);
+    transform.rotation = Quat::from_rotation_z(angle);
+    projection.scale = 1.0 - camera.zoom_punch;
+}
+
+pub fn tick_hit_stop(mut hit_stop: ResMut<HitStop>, mut time: ResMut<Time>) {
+    let raw_delta = time.raw_delta();
+    let Some(timer) = hit_stop.timer.as_mut() else {
+        return;
+    };
+
+    timer.tick(raw_delta);
+    if timer.finished() {
+        hit_stop.timer = None;
+        time.set_relative_speed(1.0);
+    }
+}
+
+/// A run starts steady, whatever shake the last one ended on.
+pub fn reset_camera_effects(mut camera_query: Query<&mut GameCamera>) {
+    if let Ok(mut camera) = camera_query.get_single_mut() {
+        camera.trauma = 0.0;
+        camera.zoom_punch = 0.0;
+    }
+}
+
+/// Leaves shake and zoom punch to wind down on their own, so the final death still lands.
+pub fn clear_hit_stop(mut hit_stop: ResMut<HitStop>, mut time: ResMut<Time>) {
+    hit_stop.timer = None;
+    time.set_relative_speed(1.0);
+}
@@ -0,0 +1,7 @@
+use bevy::prelude::Component;
+
+#[derive(Component)]
+pub struct CampaignMenu;
+
+#[derive(Component)]
+pub struct LevelCompleteMenu;
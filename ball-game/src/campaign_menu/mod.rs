@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+use systems::interactions::*;
+use systems::layout::*;
+
+use crate::AppState;
+
+mod components;
+mod systems;
+
+const STAGE_BUTTON_WIDTH: f32 = 360.0;
+
+pub struct CampaignMenuPlugin;
+
+impl Plugin for CampaignMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::Campaign), spawn_campaign_menu)
+            .add_systems(OnExit(AppState::Campaign), despawn_campaign_menu)
+            .add_systems(OnEnter(AppState::LevelComplete), spawn_level_complete_menu)
+            .add_systems(OnExit(AppState::LevelComplete), despawn_level_complete_menu)
+            // Both screens start stages.
+            .add_systems(
+                Update,
+                handle_campaign_actions.run_if(
+                    in_state(AppState::Campaign).or_else(in_state(AppState::LevelComplete)),
+                ),
+            );
+    }
+}
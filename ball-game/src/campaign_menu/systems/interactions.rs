@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+use crate::{
+    events::MenuButtonPressed,
+    game::campaign::{resources::CampaignProgress, starter::StageStarter},
+    widgets::components::MenuAction,
+};
+
+/// Locked stages still light up, but pressing them does nothing.
+pub fn handle_campaign_actions(
+    mut menu_button_event_reader: EventReader<MenuButtonPressed>,
+    mut stage_starter: StageStarter,
+    campaign_progress: Res<CampaignProgress>,
+) {
+    for event in menu_button_event_reader.iter() {
+        if let MenuAction::StartStage(stage) = event.action {
+            if stage < campaign_progress.unlocked {
+                stage_starter.start(stage);
+            }
+        }
+    }
+}
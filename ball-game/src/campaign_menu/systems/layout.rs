@@ -0,0 +1,177 @@
+use bevy::prelude::*;
+
+use crate::{
+    campaign_menu::{components::*, STAGE_BUTTON_WIDTH},
+    game::campaign::{
+        resources::{CampaignProgress, StageResult},
+        stages::{rating_label, STAGES},
+    },
+    localization::{components::LocalizedText, resources::LocalizedString},
+    main_menu::styles::{get_button_text_style, get_main_menu_style, get_title_text_style},
+    theme::{
+        components::{MenuBackground, ThemedText},
+        resources::UiTheme,
+    },
+    widgets::{builder::MenuButtonBuilder, components::MenuAction},
+    AppState,
+};
+
+fn stage_label(stage: usize, campaign_progress: &CampaignProgress) -> LocalizedString {
+    if stage < campaign_progress.unlocked {
+        LocalizedString::new("campaign.stage")
+            .with_arg("number", stage + 1)
+            .with_localized_arg("name", LocalizedString::new(STAGES[stage].name))
+            .with_arg("rating", rating_label(campaign_progress.ratings[stage]))
+    } else {
+        LocalizedString::new("campaign.stage_locked").with_arg("number", stage + 1)
+    }
+}
+
+pub fn spawn_campaign_menu(
+    mut commands: Commands,
+    ui_theme: Res<UiTheme>,
+    campaign_progress: Res<CampaignProgress>,
+) {
+    build_campaign_menu(&mut commands, &ui_theme, &campaign_progress);
+}
+
+pub fn despawn_campaign_menu(
+    mut commands: Commands,
+    campaign_menu_query: Query<Entity, With<CampaignMenu>>,
+) {
+    if let Ok(campaign_menu_entity) = campaign_menu_query.get_single() {
+        commands.entity(campaign_menu_entity).despawn_recursive();
+    }
+}
+
+pub fn build_campaign_menu(
+    commands: &mut Commands,
+    ui_theme: &UiTheme,
+    campaign_progress: &CampaignProgress,
+) -> Entity {
+    let campaign_menu_entity = commands
+        .spawn((
+            NodeBundle {
+                style: get_main_menu_style(ui_theme),
+                background_color: ui_theme.palette.background.into(),
+                ..default()
+            },
+            MenuBackground {},
+            CampaignMenu {},
+        ))
+        .with_children(|parent| {
+            // === Title ===
+            parent.spawn((
+                TextBundle::from_section("", get_title_text_style(ui_theme)),
+                LocalizedText::new(LocalizedString::new("campaign.title")),
+                ThemedText::Title,
+            ));
+            // === Stage Buttons ===
+            for stage in 0..STAGES.len() {
+                MenuButtonBuilder::new(
+                    stage_label(stage, campaign_progress),
+                    MenuAction::StartStage(stage),
+                    stage as u32,
+                )
+                .width(STAGE_BUTTON_WIDTH)
+                .spawn(parent, ui_theme);
+            }
+            // === Back Button ===
+            MenuButtonBuilder::new(
+                LocalizedString::new("common.back"),
+                MenuAction::GoTo(AppState::MainMenu),
+                STAGES.len() as u32,
+            )
+            .spawn(parent, ui_theme);
+        })
+        .id();
+
+    campaign_menu_entity
+}
+
+pub fn spawn_level_complete_menu(
+    mut commands: Commands,
+    ui_theme: Res<UiTheme>,
+    stage_result: Res<StageResult>,
+) {
+    build_level_complete_menu(&mut commands, &ui_theme, &stage_result);
+}
+
+pub fn despawn_level_complete_menu(
+    mut commands: Commands,
+    level_complete_menu_query: Query<Entity, With<LevelCompleteMenu>>,
+) {
+    if let Ok(level_complete_menu_entity) = level_complete_menu_query.get_single() {
+        commands
+            .entity(level_complete_menu_entity)
+            .despawn_recursive();
+    }
+}
+
+pub fn build_level_complete_menu(
+    commands: &mut Commands,
+    ui_theme: &UiTheme,
+    stage_result: &StageResult,
+) -> Entity {
+    let summary = [
+        LocalizedString::new(STAGES[stage_result.stage].name),
+        LocalizedString::raw(rating_label(stage_result.rating)),
+        LocalizedString::new("level_complete.time")
+            .with_arg("time", format!("{:.1}", stage_result.time)),
+        LocalizedString::new("level_complete.score").with_arg("score", stage_result.score),
+    ];
+    let has_next_stage = stage_result.stage + 1 < STAGES.len();
+
+    let level_complete_menu_entity = commands
+        .spawn((
+            NodeBundle {
+                style: get_main_menu_style(ui_theme),
+                background_color: ui_theme.palette.background.into(),
+                ..default()
+            },
+            MenuBackground {},
+            LevelCompleteMenu {},
+        ))
+        .with_children(|parent| {
+            // === Title ===
+            parent.spawn((
+                TextBundle::from_section("", get_title_text_style(ui_theme)),
+                LocalizedText::new(LocalizedString::new("level_complete.title")),
+                ThemedText::Title,
+            ));
+            // === Summary ===
+            for line in summary {
+                parent.spawn((
+                    TextBundle::from_section("", get_button_text_style(ui_theme)),
+                    LocalizedText::new(line),
+                    ThemedText::Button,
+                ));
+            }
+            // === Next Stage Button ===
+            if has_next_stage {
+                MenuButtonBuilder::new(
+                    LocalizedString::new("level_complete.next"),
+                    MenuAction::StartStage(stage_result.stage + 1),
+                    0,
+                )
+                .spawn(parent, ui_theme);
+            }
+            // === Retry Button ===
+            MenuButtonBuilder::new(
+                LocalizedString::new("level_complete.retry"),
+                MenuAction::StartStage(stage_result.stage),
+                1,
+            )
+            .spawn(parent, ui_theme);
+            // === Stages Button ===
+            MenuButtonBuilder::new(
+                LocalizedString::new("level_complete.stages"),
+                MenuAction::GoTo(AppState::Campaign),
+                2,
+            )
+            .spawn(parent, ui_theme);
+        })
+        .id();
+
+    level_complete_menu_entity
+}
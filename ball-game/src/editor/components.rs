@@ -0,0 +1,26 @@
+use bevy::prelude::Component;
+
+/// Stand-in sprite for something in the level being edited.
+#[derive(Component)]
+pub struct EditorSprite;
+
+#[derive(Component)]
+pub struct Inspector;
+
+#[derive(Component)]
+pub struct InspectorText;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InspectorAction {
+    Narrower,
+    Wider,
+    Shorter,
+    Taller,
+    CycleKind,
+    CycleDirection,
+}
+
+#[derive(Component)]
+pub struct InspectorButton {
+    pub action: InspectorAction,
+}
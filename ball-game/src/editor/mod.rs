@@ -0,0 +1,64 @@
+use bevy::prelude::*;
+use systems::editing::*;
+use systems::inspector::*;
+use systems::playtest::*;
+
+use crate::{game::level::resources::PlaytestLevel, AppState};
+
+use self::resources::EditorState;
+
+mod components;
+mod resources;
+mod systems;
+
+/// Grid spacing, in window fractions like the level format itself.
+const GRID_STEP: f32 = 0.025;
+const DEFAULT_OBSTACLE_SIZE: Vec2 = Vec2::splat(0.1);
+/// Beside the other saves rather than in `assets/`, which is part of the repository.
+const EDITOR_LEVEL_PATH: &str = "saves/custom.level.ron";
+const GRID_COLOR: Color = Color::rgba(1.0, 1.0, 1.0, 0.06);
+const SELECTION_COLOR: Color = Color::YELLOW;
+const INSPECTOR_WIDTH: f32 = 300.0;
+
+pub struct EditorPlugin;
+
+impl Plugin for EditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EditorState>()
+            .add_systems(OnEnter(AppState::Editor), (open_editor, spawn_inspector))
+            .add_systems(
+                Update,
+                (
+                    (
+                        select_tool,
+                        toggle_snap,
+                        edit_with_mouse,
+                        delete_selection,
+                        save_or_load_level,
+                        interact_with_inspector_buttons,
+                    )
+                        .chain(),
+                    (update_inspector_text, sync_editor_sprites),
+                    draw_editor_gizmos,
+                    start_playtest,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Editor)),
+            )
+            .add_systems(
+                Update,
+                stop_playtest
+                    .run_if(in_state(AppState::InGame))
+                    .run_if(resource_exists::<PlaytestLevel>()),
+            )
+            .add_systems(
+                OnEnter(AppState::GameOver),
+                return_to_editor.run_if(resource_exists::<PlaytestLevel>()),
+            )
+            .add_systems(OnEnter(AppState::MainMenu), clear_playtest_level)
+            .add_systems(
+                OnExit(AppState::Editor),
+                (despawn_inspector, despawn_editor_sprites),
+            );
+    }
+}
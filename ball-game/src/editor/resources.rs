@@ -0,0 +1,187 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::{
+    game::{
+        enemy::{archetypes::ARCHETYPES, components::EnemyKind},
+        level::asset::{Area, EnemyPlacement, Level},
+    },
+    localization::resources::LocalizedString,
+    save::files::{read_ron, write_ron},
+};
+
+use super::{EDITOR_LEVEL_PATH, GRID_STEP};
+
+/// Enemy directions the inspector cycles through, besides random.
+const DIRECTIONS: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EditorTool {
+    #[default]
+    Obstacle,
+    Star,
+    Enemy,
+}
+
+impl EditorTool {
+    /// String table key.
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            EditorTool::Obstacle => "editor.tool.obstacle",
+            EditorTool::Star => "editor.tool.star",
+            EditorTool::Enemy => "editor.tool.enemy",
+        }
+    }
+}
+
+/// Index into one of the level's lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditorItem {
+    Obstacle(usize),
+    Star(usize),
+    Enemy(usize),
+}
+
+#[derive(Resource)]
+pub struct EditorState {
+    pub level: Level,
+    pub tool: EditorTool,
+    pub selection: Option<EditorItem>,
+    /// Whether the selection follows the cursor until the mouse button is released.
+    pub dragging: bool,
+    pub snap: bool,
+}
+
+impl Default for EditorState {
+    fn default() -> Self {
+        EditorState {
+            level: Level {
+                name: "Custom".to_string(),
+                ..default()
+            },
+            tool: EditorTool::default(),
+            selection: None,
+            dragging: false,
+            snap: true,
+        }
+    }
+}
+
+impl EditorState {
+    pub fn snapped(&self, position: Vec2) -> Vec2 {
+        if self.snap {
+            (position / GRID_STEP).round() * GRID_STEP
+        } else {
+            position
+        }
+    }
+
+    pub fn position(&self, item: EditorItem) -> Vec2 {
+        match item {
+            EditorItem::Obstacle(index) => self.level.obstacles[index].center,
+            EditorItem::Star(index) => self.level.stars[index],
+            EditorItem::Enemy(index) => self.level.enemies[index].position,
+        }
+    }
+
+    pub fn set_position(&mut self, item: EditorItem, position: Vec2) {
+        match item {
+            EditorItem::Obstacle(index) => self.level.obstacles[index].center = position,
+            EditorItem::Star(index) => self.level.stars[index] = position,
+            EditorItem::Enemy(index) => self.level.enemies[index].position = position,
+        }
+    }
+
+    /// Adds an item of the current tool's kind, returning it.
+    pub fn place(&mut self, position: Vec2, obstacle_size: Vec2) -> EditorItem {
+        match self.tool {
+            EditorTool::Obstacle => {
+                self.level.obstacles.push(Area {
+                    center: position,
+                    size: obstacle_size,
+                });
+                EditorItem::Obstacle(self.level.obstacles.len() - 1)
+            }
+            EditorTool::Star => {
+                self.level.stars.push(position);
+                EditorItem::Star(self.level.stars.len() - 1)
+            }
+            EditorTool::Enemy => {
+                self.level.enemies.push(EnemyPlacement {
+                    kind: EnemyKind::Bouncer,
+                    position,
+                    direction: None,
+                });
+                EditorItem::Enemy(self.level.enemies.len() - 1)
+            }
+        }
+    }
+
+    pub fn remove(&mut self, item: EditorItem) {
+        match item {
+            EditorItem::Obstacle(index) => {
+                self.level.obstacles.remove(index);
+            }
+            EditorItem::Star(index) => {
+                self.level.stars.remove(index);
+            }
+            EditorItem::Enemy(index) => {
+                self.level.enemies.remove(index);
+            }
+        }
+        self.selection = None;
+        self.dragging = false;
+    }
+
+    pub fn save(&self) -> bool {
+        write_ron(EDITOR_LEVEL_PATH, &self.level)
+    }
+
+    /// Leaves the level as it is when there's none saved.
+    pub fn load(&mut self) -> bool {
+        match read_ron(EDITOR_LEVEL_PATH) {
+            Some(level) => {
+                self.level = level;
+                self.selection = None;
+                self.dragging = false;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+pub fn next_enemy_kind(kind: EnemyKind) -> EnemyKind {
+    let index = ARCHETYPES
+        .iter()
+        .position(|archetype| archetype.kind == kind)
+        .unwrap_or_default();
+    ARCHETYPES[(index + 1) % ARCHETYPES.len()].kind
+}
+
+/// Steps clockwise through the compass directions, with random between the last and the first.
+pub fn next_direction(direction: Option<Vec2>) -> Option<Vec2> {
+    let step = TAU / DIRECTIONS as f32;
+    let Some(direction) = direction else {
+        return Some(Vec2::Y);
+    };
+    let index = (direction.y.atan2(direction.x) / step).round() as i32;
+    let next = (index - 1).rem_euclid(DIRECTIONS as i32);
+    // Pointing up again means we went all the way around.
+    if next == DIRECTIONS as i32 / 4 {
+        None
+    } else {
+        Some(Vec2::from_angle(next as f32 * step))
+    }
+}
+
+pub fn direction_label(direction: Option<Vec2>) -> LocalizedString {
+    match direction {
+        Some(direction) => {
+            let degrees = direction.y.atan2(direction.x).to_degrees().round();
+            LocalizedString::new("editor.direction.degrees").with_arg("degrees", degrees)
+        }
+        None => LocalizedString::new("editor.direction.random"),
+    }
+}
@@ -0,0 +1,303 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{
+    camera::components::GameCamera,
+    editor::{
+        components::{EditorSprite, Inspector, InspectorButton},
+        resources::{EditorItem, EditorState, EditorTool},
+        DEFAULT_OBSTACLE_SIZE, EDITOR_LEVEL_PATH, GRID_COLOR, GRID_STEP, SELECTION_COLOR,
+    },
+    game::{
+        enemy::archetypes::archetype,
+        level::{
+            active::{to_world, ActiveLevel},
+            resources::PlaytestLevel,
+            OBSTACLE_COLOR,
+        },
+        star::STAR_SIZE,
+    },
+    loading::resources::GameAssets,
+    localization::{console::PrintLocalized, resources::LocalizedString},
+};
+
+type InspectorUi = Or<(With<Inspector>, With<InspectorButton>)>;
+
+fn window_size(window: &Window) -> Vec2 {
+    Vec2::new(window.width(), window.height())
+}
+
+/// The item under `position` (in world coordinates), preferring the smaller ones on top.
+fn item_at(editor_state: &EditorState, position: Vec2, window: &Window) -> Option<EditorItem> {
+    let level = &editor_state.level;
+    let enemy = level.enemies.iter().rposition(|placement| {
+        let radius = archetype(placement.kind).size / 2.0;
+        to_world(placement.position, window).distance(position) < radius
+    });
+    let star = level
+        .stars
+        .iter()
+        .rposition(|star| to_world(*star, window).distance(position) < STAR_SIZE / 2.0);
+    let obstacle = level
+        .obstacles
+        .iter()
+        .rposition(|area| area.rect(window).contains(position));
+
+    enemy
+        .map(EditorItem::Enemy)
+        .or_else(|| star.map(EditorItem::Star))
+        .or_else(|| obstacle.map(EditorItem::Obstacle))
+}
+
+/// Starts from the selected level's layout, or picks up where a test play left off.
+pub fn open_editor(
+    mut commands: Commands,
+    mut editor_state: ResMut<EditorState>,
+    playtest_level: Option<Res<PlaytestLevel>>,
+    active_level: ActiveLevel,
+) {
+    if playtest_level.is_some() {
+        commands.remove_resource::<PlaytestLevel>();
+    } else {
+        *editor_state = EditorState::default();
+        if let Some(level) = active_level.selected() {
+            editor_state.level = level.clone();
+        }
+    }
+    editor_state.dragging = false;
+}
+
+pub fn select_tool(keyboard_input: Res<Input<KeyCode>>, mut editor_state: ResMut<EditorState>) {
+    let tool = if keyboard_input.just_pressed(KeyCode::Key1) {
+        EditorTool::Obstacle
+    } else if keyboard_input.just_pressed(KeyCode::Key2) {
+        EditorTool::Star
+    } else if keyboard_input.just_pressed(KeyCode::Key3) {
+        EditorTool::Enemy
+    } else {
+        return;
+    };
+    editor_state.tool = tool;
+}
+
+pub fn toggle_snap(keyboard_input: Res<Input<KeyCode>>, mut editor_state: ResMut<EditorState>) {
+    if keyboard_input.just_pressed(KeyCode::N) {
+        editor_state.snap = !editor_state.snap;
+    }
+}
+
+/// Left click selects or places and drags to move, right click removes.
+pub fn edit_with_mouse(
+    mouse_input: Res<Input<MouseButton>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<GameCamera>>,
+    inspector_query: Query<&Interaction, InspectorUi>,
+    mut editor_state: ResMut<EditorState>,
+) {
+    if mouse_input.just_released(MouseButton::Left) && editor_state.dragging {
+        editor_state.dragging = false;
+    }
+
+    let (Ok(window), Ok((camera, camera_transform))) =
+        (window_query.get_single(), camera_query.get_single())
+    else {
+        return;
+    };
+    let Some(cursor_position) = window
+        .cursor_position()
+        .and_then(|position| camera.viewport_to_world_2d(camera_transform, position))
+    else {
+        return;
+    };
+    let over_inspector = inspector_query
+        .iter()
+        .any(|interaction| *interaction != Interaction::None);
+    let level_position = editor_state.snapped(cursor_position / window_size(window));
+
+    if mouse_input.just_pressed(MouseButton::Left) && !over_inspector {
+        let item = match item_at(&editor_state, cursor_position, window) {
+            Some(item) => item,
+            None => editor_state.place(level_position, DEFAULT_OBSTACLE_SIZE),
+        };
+        editor_state.selection = Some(item);
+        editor_state.dragging = true;
+    } else if mouse_input.pressed(MouseButton::Left) && editor_state.dragging {
+        if let Some(item) = editor_state.selection {
+            // Only touch the state when something moved, so the sprites aren't rebuilt every frame.
+            if editor_state.position(item) != level_position {
+                editor_state.set_position(item, level_position);
+            }
+        }
+    }
+
+    if mouse_input.just_pressed(MouseButton::Right) && !over_inspector {
+        if let Some(item) = item_at(&editor_state, cursor_position, window) {
+            editor_state.remove(item);
+        }
+    }
+}
+
+pub fn delete_selection(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut editor_state: ResMut<EditorState>,
+) {
+    if keyboard_input.any_just_pressed([KeyCode::Delete, KeyCode::Back]) {
+        if let Some(item) = editor_state.selection {
+            editor_state.remove(item);
+        }
+    }
+}
+
+pub fn save_or_load_level(
+    mut commands: Commands,
+    keyboard_input: Res<Input<KeyCode>>,
+    mut editor_state: ResMut<EditorState>,
+) {
+    if keyboard_input.just_pressed(KeyCode::S) && editor_state.save() {
+        commands.add(PrintLocalized(
+            LocalizedString::new("console.level_saved").with_arg("path", EDITOR_LEVEL_PATH),
+        ));
+    }
+    if keyboard_input.just_pressed(KeyCode::L) {
+        let key = if editor_state.load() {
+            "console.level_loaded"
+        } else {
+            "console.no_level"
+        };
+        commands.add(PrintLocalized(
+            LocalizedString::new(key).with_arg("path", EDITOR_LEVEL_PATH),
+        ));
+    }
+}
+
+/// Rebuilds the stand-in sprites whenever the layout changes.
+pub fn sync_editor_sprites(
+    mut commands: Commands,
+    sprite_query: Query<Entity, With<EditorSprite>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    editor_state: Res<EditorState>,
+    game_assets: Res<GameAssets>,
+) {
+    if !editor_state.is_changed() {
+        return;
+    }
+    for sprite_entity in sprite_query.iter() {
+        commands.entity(sprite_entity).despawn();
+    }
+
+    let window = window_query.get_single().unwrap();
+    let level = &editor_state.level;
+
+    for area in level.obstacles.iter() {
+        let rect = area.rect(window);
+        let center = rect.center();
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: OBSTACLE_COLOR,
+                    custom_size: Some(rect.size()),
+                    ..default()
+                },
+                transform: Transform::from_xyz(center.x, center.y, 0.0),
+                ..default()
+            },
+            EditorSprite {},
+        ));
+    }
+    for star in level.stars.iter() {
+        let position = to_world(*star, window);
+        commands.spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(position.x, position.y, 0.1),
+                texture: game_assets.star_texture.clone(),
+                ..default()
+            },
+            EditorSprite {},
+        ));
+    }
+    for placement in level.enemies.iter() {
+        let archetype = archetype(placement.kind);
+        let position = to_world(placement.position, window);
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: archetype.color,
+                    custom_size: Some(Vec2::splat(archetype.size)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(position.x, position.y, 0.2),
+                texture: game_assets.enemy_texture.clone(),
+                ..default()
+            },
+            EditorSprite {},
+        ));
+    }
+}
+
+pub fn despawn_editor_sprites(
+    mut commands: Commands,
+    sprite_query: Query<Entity, With<EditorSprite>>,
+) {
+    for sprite_entity in sprite_query.iter() {
+        commands.entity(sprite_entity).despawn();
+    }
+}
+
+/// Grid, selection outline and the direction each enemy spawner starts moving in.
+pub fn draw_editor_gizmos(
+    mut gizmos: Gizmos,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    editor_state: Res<EditorState>,
+) {
+    let window = window_query.get_single().unwrap();
+    let size = window_size(window);
+
+    if editor_state.snap {
+        let lines = (1.0 / GRID_STEP).round() as usize;
+        for i in 1..lines {
+            let fraction = i as f32 * GRID_STEP;
+            gizmos.line_2d(
+                Vec2::new(fraction * size.x, 0.0),
+                Vec2::new(fraction * size.x, size.y),
+                GRID_COLOR,
+            );
+            gizmos.line_2d(
+                Vec2::new(0.0, fraction * size.y),
+                Vec2::new(size.x, fraction * size.y),
+                GRID_COLOR,
+            );
+        }
+    }
+
+    for placement in editor_state.level.enemies.iter() {
+        if let Some(direction) = placement.direction {
+            let position = to_world(placement.position, window);
+            let length = archetype(placement.kind).size;
+            gizmos.line_2d(
+                position,
+                position + direction.normalize_or_zero() * length,
+                Color::WHITE,
+            );
+        }
+    }
+
+    match editor_state.selection {
+        Some(EditorItem::Obstacle(index)) => {
+            let rect = editor_state.level.obstacles[index].rect(window);
+            gizmos.rect_2d(rect.center(), 0.0, rect.size(), SELECTION_COLOR);
+        }
+        Some(EditorItem::Star(index)) => {
+            let position = to_world(editor_state.level.stars[index], window);
+            gizmos.circle_2d(position, STAR_SIZE / 2.0, SELECTION_COLOR);
+        }
+        Some(EditorItem::Enemy(index)) => {
+            let placement = &editor_state.level.enemies[index];
+            let position = to_world(placement.position, window);
+            gizmos.circle_2d(
+                position,
+                archetype(placement.kind).size / 2.0,
+                SELECTION_COLOR,
+            );
+        }
+        None => {}
+    }
+}
@@ -0,0 +1,211 @@
+use bevy::prelude::*;
+
+use crate::{
+    editor::{
+        components::{Inspector, InspectorAction, InspectorButton, InspectorText},
+        resources::{direction_label, next_direction, next_enemy_kind, EditorItem, EditorState},
+        GRID_STEP, INSPECTOR_WIDTH,
+    },
+    localization::{components::LocalizedText, resources::LocalizedString},
+    theme::resources::UiTheme,
+};
+
+const INSPECTOR_BUTTONS: [(InspectorAction, &str); 6] = [
+    (InspectorAction::Narrower, "editor.narrower"),
+    (InspectorAction::Wider, "editor.wider"),
+    (InspectorAction::Shorter, "editor.shorter"),
+    (InspectorAction::Taller, "editor.taller"),
+    (InspectorAction::CycleKind, "editor.enemy_type"),
+    (InspectorAction::CycleDirection, "editor.direction"),
+];
+
+fn get_inspector_style() -> Style {
+    Style {
+        position_type: PositionType::Absolute,
+        right: Val::Px(0.0),
+        width: Val::Px(INSPECTOR_WIDTH),
+        height: Val::Percent(100.0),
+        flex_direction: FlexDirection::Column,
+        padding: UiRect::all(Val::Px(12.0)),
+        row_gap: Val::Px(8.0),
+        ..default()
+    }
+}
+
+fn get_inspector_button_style() -> Style {
+    Style {
+        width: Val::Px(INSPECTOR_WIDTH / 2.0 - 16.0),
+        height: Val::Px(36.0),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    }
+}
+
+fn get_inspector_text_style(ui_theme: &UiTheme) -> TextStyle {
+    TextStyle {
+        font: ui_theme.font.clone(),
+        font_size: 18.0,
+        color: ui_theme.palette.text,
+    }
+}
+
+/// The level and tool, what's selected, then the controls; one text section each.
+fn inspector_label(editor_state: &EditorState) -> Vec<LocalizedString> {
+    let snap = if editor_state.snap {
+        "settings.on"
+    } else {
+        "settings.off"
+    };
+    let header = LocalizedString::new("editor.inspector")
+        .with_arg("level", &editor_state.level.name)
+        .with_localized_arg("tool", LocalizedString::new(editor_state.tool.label_key()))
+        .with_localized_arg("snap", LocalizedString::new(snap));
+
+    let selection = match editor_state.selection {
+        Some(EditorItem::Obstacle(index)) => {
+            let area = &editor_state.level.obstacles[index];
+            LocalizedString::new("editor.selected.obstacle")
+                .with_arg("x", format!("{:.3}", area.center.x))
+                .with_arg("y", format!("{:.3}", area.center.y))
+                .with_arg("width", format!("{:.3}", area.size.x))
+                .with_arg("height", format!("{:.3}", area.size.y))
+        }
+        Some(EditorItem::Star(index)) => {
+            let star = editor_state.level.stars[index];
+            LocalizedString::new("editor.selected.star")
+                .with_arg("x", format!("{:.3}", star.x))
+                .with_arg("y", format!("{:.3}", star.y))
+        }
+        Some(EditorItem::Enemy(index)) => {
+            let placement = &editor_state.level.enemies[index];
+            LocalizedString::new("editor.selected.enemy")
+                .with_arg("x", format!("{:.3}", placement.position.x))
+                .with_arg("y", format!("{:.3}", placement.position.y))
+                .with_arg("kind", format!("{:?}", placement.kind))
+                .with_localized_arg("direction", direction_label(placement.direction))
+        }
+        None => LocalizedString::new("editor.selected.none"),
+    };
+
+    vec![header, selection, LocalizedString::new("editor.help")]
+}
+
+pub fn spawn_inspector(
+    mut commands: Commands,
+    ui_theme: Res<UiTheme>,
+    editor_state: Res<EditorState>,
+) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: get_inspector_style(),
+                background_color: ui_theme.palette.panel.into(),
+                ..default()
+            },
+            // Lets clicks on the panel be told apart from clicks on the level.
+            Interaction::default(),
+            Inspector {},
+        ))
+        .with_children(|parent| {
+            let text_style = get_inspector_text_style(&ui_theme);
+            parent.spawn((
+                TextBundle::from_sections([
+                    TextSection::new("", text_style.clone()),
+                    TextSection::new("", text_style.clone()),
+                    TextSection::new("", text_style),
+                ]),
+                LocalizedText {
+                    sections: inspector_label(&editor_state),
+                },
+                InspectorText {},
+            ));
+            // === Inspector Buttons ===
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_wrap: FlexWrap::Wrap,
+                        row_gap: Val::Px(8.0),
+                        column_gap: Val::Px(8.0),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for (action, label) in INSPECTOR_BUTTONS {
+                        parent
+                            .spawn((
+                                ButtonBundle {
+                                    style: get_inspector_button_style(),
+                                    background_color: ui_theme.palette.button.into(),
+                                    ..default()
+                                },
+                                InspectorButton { action },
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    TextBundle::from_section(
+                                        "",
+                                        get_inspector_text_style(&ui_theme),
+                                    ),
+                                    LocalizedText::new(LocalizedString::new(label)),
+                                ));
+                            });
+                    }
+                });
+        });
+}
+
+pub fn despawn_inspector(mut commands: Commands, inspector_query: Query<Entity, With<Inspector>>) {
+    if let Ok(inspector_entity) = inspector_query.get_single() {
+        commands.entity(inspector_entity).despawn_recursive();
+    }
+}
+
+/// Edits the selected item; buttons that don't apply to it do nothing.
+pub fn interact_with_inspector_buttons(
+    button_query: Query<(&Interaction, &InspectorButton), Changed<Interaction>>,
+    mut editor_state: ResMut<EditorState>,
+) {
+    for (interaction, inspector_button) in button_query.iter() {
+        if *interaction == Interaction::Pressed {
+            apply_action(&mut editor_state, inspector_button.action);
+        }
+    }
+}
+
+fn apply_action(editor_state: &mut EditorState, action: InspectorAction) {
+    match (editor_state.selection, action) {
+        (Some(EditorItem::Obstacle(index)), _) => {
+            let size = &mut editor_state.level.obstacles[index].size;
+            let change = match action {
+                InspectorAction::Narrower => Vec2::new(-GRID_STEP, 0.0),
+                InspectorAction::Wider => Vec2::new(GRID_STEP, 0.0),
+                InspectorAction::Shorter => Vec2::new(0.0, -GRID_STEP),
+                InspectorAction::Taller => Vec2::new(0.0, GRID_STEP),
+                InspectorAction::CycleKind | InspectorAction::CycleDirection => return,
+            };
+            *size = (*size + change).max(Vec2::splat(GRID_STEP));
+        }
+        (Some(EditorItem::Enemy(index)), InspectorAction::CycleKind) => {
+            let placement = &mut editor_state.level.enemies[index];
+            placement.kind = next_enemy_kind(placement.kind);
+        }
+        (Some(EditorItem::Enemy(index)), InspectorAction::CycleDirection) => {
+            let placement = &mut editor_state.level.enemies[index];
+            placement.direction = next_direction(placement.direction);
+        }
+        _ => {}
+    }
+}
+
+pub fn update_inspector_text(
+    mut text_query: Query<&mut LocalizedText, With<InspectorText>>,
+    editor_state: Res<EditorState>,
+) {
+    if editor_state.is_changed() {
+        if let Ok(mut text) = text_query.get_single_mut() {
+            text.sections = inspector_label(&editor_state);
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod editing;
+pub mod inspector;
+pub mod playtest;
@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+use crate::{editor::resources::EditorState, game::level::resources::PlaytestLevel, AppState};
+
+pub fn start_playtest(
+    mut commands: Commands,
+    keyboard_input: Res<Input<KeyCode>>,
+    editor_state: Res<EditorState>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::P) {
+        commands.insert_resource(PlaytestLevel(editor_state.level.clone()));
+        next_app_state.set(AppState::InGame);
+    }
+}
+
+pub fn stop_playtest(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::P) {
+        next_app_state.set(AppState::Editor);
+    }
+}
+
+/// Dying in a test play goes straight back to editing.
+pub fn return_to_editor(mut next_app_state: ResMut<NextState<AppState>>) {
+    next_app_state.set(AppState::Editor);
+}
+
+/// Leaving for the main menu mid test play mustn't turn the next run into the test level.
+pub fn clear_playtest_level(mut commands: Commands) {
+    commands.remove_resource::<PlaytestLevel>();
+}
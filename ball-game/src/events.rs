@@ -1,6 +1,86 @@
 use bevy::prelude::*;
 
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    achievements::definitions::AchievementId,
+    game::{enemy::components::EnemyKind, particle::components::ParticleEffect},
+    widgets::components::MenuAction,
+};
+
 #[derive(Event)]
 pub struct GameOver {
     pub score: u32,
+    /// The surviving player in versus mode; `None` otherwise or on a draw.
+    pub winner: Option<usize>,
+}
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct AchievementUnlocked {
+    pub achievement: AchievementId,
+}
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct PlayerDied {
+    pub player: usize,
+    pub cause: DeathCause,
+}
+
+/// A player picked up a star.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct StarCollected;
+
+/// An enemy bounced off an arena edge or an obstacle.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct EnemyBounced;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DeathCause {
+    Enemy(EnemyKind),
+    BossBullet,
+}
+
+impl DeathCause {
+    /// String table key.
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            DeathCause::Enemy(EnemyKind::Bouncer) => "death_cause.bouncer",
+            DeathCause::Enemy(EnemyKind::Chaser) => "death_cause.chaser",
+            DeathCause::Enemy(EnemyKind::Drifter) => "death_cause.drifter",
+            DeathCause::Enemy(EnemyKind::Splitter) => "death_cause.splitter",
+            DeathCause::Enemy(EnemyKind::Dasher) => "death_cause.dasher",
+            DeathCause::BossBullet => "death_cause.boss_bullet",
+        }
+    }
+}
+
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SoundEvent {
+    EnemyBounce,
+    StarCollected,
+    PlayerDeath,
+    BossHit,
+}
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ParticleEvent {
+    pub effect: ParticleEffect,
+    pub position: Vec2,
+    /// Emission direction; zero lets the effect pick its own.
+    pub direction: Vec2,
+}
+
+#[derive(Event, Debug, Clone, Copy)]
+pub enum CameraEvent {
+    /// Adds trauma to the camera shake.
+    Shake(f32),
+    /// Slows the game down for the given duration, in seconds.
+    HitStop(f32),
+    /// Zooms in by the given fraction and eases back out.
+    ZoomPunch(f32),
+}
+
+#[derive(Event, Debug, Clone)]
+pub struct MenuButtonPressed {
+    pub action: MenuAction,
 }
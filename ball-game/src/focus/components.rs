@@ -0,0 +1,8 @@
+use bevy::prelude::Component;
+
+/// A button that can be reached with the keyboard. Focus moves through the buttons on screen
+/// from the lowest `order` to the highest.
+#[derive(Component)]
+pub struct Focusable {
+    pub order: u32,
+}
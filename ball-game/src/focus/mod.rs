@@ -0,0 +1,31 @@
+use bevy::{prelude::*, ui::UiSystem};
+
+use self::{resources::Focus, systems::*};
+use crate::theme::resources::UiTheme;
+
+pub mod components;
+mod resources;
+mod systems;
+
+/// Keyboard navigation for menu buttons: arrows or Tab move the focus, Enter or Space press
+/// (just Enter while typing a profile name).
+pub struct FocusPlugin;
+
+impl Plugin for FocusPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Focus>()
+            .add_systems(
+                PreUpdate,
+                (move_focus, activate_focus)
+                    .chain()
+                    .after(UiSystem::Focus)
+                    .run_if(resource_exists::<UiTheme>()),
+            )
+            .add_systems(
+                PostUpdate,
+                (release_focus, highlight_focus)
+                    .chain()
+                    .run_if(resource_exists::<UiTheme>()),
+            );
+    }
+}
@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+#[derive(Resource, Default)]
+pub struct Focus {
+    /// Nothing is focused until a navigation key is pressed.
+    pub entity: Option<Entity>,
+    /// Button pressed with the keyboard this frame, to be released once everyone has seen it.
+    pub pressed: Option<Entity>,
+}
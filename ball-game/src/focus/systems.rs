@@ -0,0 +1,114 @@
+use bevy::prelude::*;
+
+use super::{components::Focusable, resources::Focus};
+use crate::{theme::resources::UiTheme, AppState};
+
+fn focus_step(keyboard_input: &Input<KeyCode>) -> Option<isize> {
+    let shift = keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    if keyboard_input.any_just_pressed([KeyCode::Down, KeyCode::Right])
+        || (keyboard_input.just_pressed(KeyCode::Tab) && !shift)
+    {
+        Some(1)
+    } else if keyboard_input.any_just_pressed([KeyCode::Up, KeyCode::Left])
+        || (keyboard_input.just_pressed(KeyCode::Tab) && shift)
+    {
+        Some(-1)
+    } else {
+        None
+    }
+}
+
+pub fn move_focus(
+    mut focusable_query: Query<(Entity, &Focusable, &Interaction, &mut BackgroundColor)>,
+    mut focus: ResMut<Focus>,
+    keyboard_input: Res<Input<KeyCode>>,
+    ui_theme: Res<UiTheme>,
+) {
+    let Some(step) = focus_step(&keyboard_input) else {
+        return;
+    };
+
+    let mut focusables: Vec<(u32, Entity)> = focusable_query
+        .iter()
+        .map(|(entity, focusable, _, _)| (focusable.order, entity))
+        .collect();
+    if focusables.is_empty() {
+        return;
+    }
+    focusables.sort();
+
+    // Starts from the first button, or the last one going backwards.
+    let current = focus
+        .entity
+        .and_then(|focused| focusables.iter().position(|&(_, entity)| entity == focused));
+    let next = match current {
+        Some(index) => (index as isize + step).rem_euclid(focusables.len() as isize) as usize,
+        None if step > 0 => 0,
+        None => focusables.len() - 1,
+    };
+
+    if let Some(previous) = focus.entity {
+        if let Ok((_, _, Interaction::None, mut background_color)) =
+            focusable_query.get_mut(previous)
+        {
+            *background_color = ui_theme.palette.button.into();
+        }
+    }
+    focus.entity = Some(focusables[next].1);
+}
+
+/// Presses the focused button like a click would, so the menus' interaction systems handle it.
+pub fn activate_focus(
+    mut interaction_query: Query<&mut Interaction, With<Focusable>>,
+    mut focus: ResMut<Focus>,
+    keyboard_input: Res<Input<KeyCode>>,
+    app_state: Res<State<AppState>>,
+) {
+    // Space goes into the name being typed on the profile select screen.
+    let activation_keys: &[KeyCode] = match app_state.get() {
+        AppState::ProfileSelect => &[KeyCode::Return],
+        _ => &[KeyCode::Return, KeyCode::Space],
+    };
+    if !keyboard_input.any_just_pressed(activation_keys.iter().copied()) {
+        return;
+    }
+    let Some(focused) = focus.entity else {
+        return;
+    };
+    if let Ok(mut interaction) = interaction_query.get_mut(focused) {
+        *interaction = Interaction::Pressed;
+        focus.pressed = Some(focused);
+    }
+}
+
+/// The mouse releases what it pressed, but nothing does for keyboard presses.
+pub fn release_focus(
+    mut interaction_query: Query<&mut Interaction, With<Focusable>>,
+    mut focus: ResMut<Focus>,
+) {
+    let Some(pressed) = focus.pressed.take() else {
+        return;
+    };
+    if let Ok(mut interaction) = interaction_query.get_mut(pressed) {
+        if *interaction == Interaction::Pressed {
+            *interaction = Interaction::None;
+        }
+    }
+}
+
+/// Runs after the menus' interaction systems, which reset the color of buttons the mouse isn't
+/// over.
+pub fn highlight_focus(
+    mut button_query: Query<(&Interaction, &mut BackgroundColor), With<Focusable>>,
+    focus: Res<Focus>,
+    ui_theme: Res<UiTheme>,
+) {
+    let Some(focused) = focus.entity else {
+        return;
+    };
+    if let Ok((Interaction::None, mut background_color)) = button_query.get_mut(focused) {
+        if background_color.0 != ui_theme.palette.button_hovered {
+            *background_color = ui_theme.palette.button_hovered.into();
+        }
+    }
+}
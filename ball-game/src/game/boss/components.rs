@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BossPhase {
+    /// Fires rings of bullets outwards.
+    BulletRing,
+    /// Summons regular enemies.
+    Summon,
+    /// Glows and takes damage when the player touches it.
+    Vulnerable,
+}
+
+impl BossPhase {
+    pub fn next(&self) -> BossPhase {
+        match self {
+            BossPhase::BulletRing => BossPhase::Summon,
+            BossPhase::Summon => BossPhase::Vulnerable,
+            BossPhase::Vulnerable => BossPhase::BulletRing,
+        }
+    }
+}
+
+#[derive(Component, Clone, Serialize, Deserialize)]
+pub struct Boss {
+    pub health: u32,
+    pub max_health: u32,
+    pub phase: BossPhase,
+    pub phase_timer: Timer,
+    pub attack_timer: Timer,
+    /// Surviving until this runs out ends the encounter.
+    pub encounter_timer: Timer,
+    pub anchor: Vec2,
+}
+
+#[derive(Component)]
+pub struct BossHealthBar;
+
+#[derive(Component, Clone, Serialize, Deserialize)]
+pub struct BossBullet {
+    pub velocity: Vec2,
+}
@@ -0,0 +1,71 @@
+use bevy::prelude::*;
+
+use crate::{save::resources::ResumeRun, AppState};
+
+use self::{
+    resources::{BossEncounter, BossSchedule},
+    systems::*,
+};
+
+use super::SimulationState;
+
+pub mod components;
+pub mod resources;
+mod systems;
+
+pub use systems::{spawn_boss, spawn_bullet};
+
+/// Score milestones at which a boss shows up, spaced wide enough that one boss's bonus
+/// doesn't bring on the next.
+const BOSS_THRESHOLDS: [u32; 3] = [25, 200, 400];
+/// A star is worth 1, so beating a boss outweighs a long stretch of pickups.
+const BOSS_DEFEAT_BONUS: u32 = 100;
+const BOSS_SURVIVE_BONUS: u32 = 40;
+const BOSS_SIZE: f32 = 160.0;
+const BOSS_HEALTH: u32 = 3;
+const BOSS_COLOR: Color = Color::rgb(1.0, 0.4, 0.4);
+const BOSS_VULNERABLE_COLOR: Color = Color::rgb(1.0, 1.0, 0.5);
+/// How long the player has to survive before the boss leaves, in seconds.
+const BOSS_ENCOUNTER_TIME: f32 = 45.0;
+const BOSS_PHASE_TIME: f32 = 6.0;
+const BOSS_VULNERABLE_TIME: f32 = 3.0;
+const BULLET_RING_INTERVAL: f32 = 1.2;
+const SUMMON_INTERVAL: f32 = 2.0;
+const BULLETS_PER_RING: usize = 12;
+const BULLET_SPEED: f32 = 220.0;
+const BULLET_SIZE: f32 = 16.0;
+/// Distance the boss wanders from the centre of the window.
+const BOSS_WANDER_RADIUS: f32 = 180.0;
+const HEALTH_BAR_WIDTH: f32 = 140.0;
+const HEALTH_BAR_HEIGHT: f32 = 10.0;
+
+pub struct BossPlugin;
+
+impl Plugin for BossPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BossSchedule>()
+            .init_resource::<BossEncounter>()
+            .add_systems(
+                OnEnter(AppState::InGame),
+                reset_boss_schedule.run_if(not(resource_exists::<ResumeRun>())),
+            )
+            .add_systems(
+                Update,
+                (
+                    start_boss_encounter,
+                    boss_movement,
+                    update_boss_phase,
+                    boss_attacks,
+                    bullet_movement,
+                    update_boss_health_bar,
+                    player_hit_boss,
+                    player_hit_bullets,
+                    end_boss_encounter,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::InGame))
+                    .run_if(in_state(SimulationState::Running)),
+            )
+            .add_systems(OnExit(AppState::InGame), despawn_boss);
+    }
+}
@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::BOSS_THRESHOLDS;
+
+/// Score milestones at which a boss shows up, in increasing order.
+#[derive(Resource, Clone, Serialize, Deserialize)]
+pub struct BossSchedule {
+    pub thresholds: Vec<u32>,
+    pub next: usize,
+}
+
+impl Default for BossSchedule {
+    fn default() -> Self {
+        BossSchedule {
+            thresholds: BOSS_THRESHOLDS.to_vec(),
+            next: 0,
+        }
+    }
+}
+
+impl BossSchedule {
+    pub fn next_threshold(&self) -> Option<u32> {
+        self.thresholds.get(self.next).copied()
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct BossEncounter {
+    pub active: bool,
+}
+
+/// Run condition pausing regular spawning while a boss is around.
+pub fn no_boss_encounter(boss_encounter: Res<BossEncounter>) -> bool {
+    !boss_encounter.active
+}
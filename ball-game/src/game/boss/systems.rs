@@ -0,0 +1,394 @@
+use std::f32::consts::TAU;
+
+use bevy::{prelude::*, sprite::Anchor, window::PrimaryWindow};
+
+use super::{
+    components::{Boss, BossBullet, BossHealthBar, BossPhase},
+    resources::{BossEncounter, BossSchedule},
+    BOSS_COLOR, BOSS_DEFEAT_BONUS, BOSS_ENCOUNTER_TIME, BOSS_HEALTH, BOSS_PHASE_TIME, BOSS_SIZE,
+    BOSS_SURVIVE_BONUS, BOSS_VULNERABLE_COLOR, BOSS_VULNERABLE_TIME, BOSS_WANDER_RADIUS,
+    BULLETS_PER_RING, BULLET_RING_INTERVAL, BULLET_SIZE, BULLET_SPEED, HEALTH_BAR_HEIGHT,
+    HEALTH_BAR_WIDTH, SUMMON_INTERVAL,
+};
+use crate::{
+    events::{CameraEvent, DeathCause, ParticleEvent, SoundEvent},
+    game::{
+        enemy::{
+            archetypes::archetype, components::EnemyKind, direction::random_direction,
+            resources::EnemySpawnTimer, spawn_enemy,
+        },
+        particle::components::ParticleEffect,
+        player::{
+            components::{Invulnerable, Player},
+            death::PlayerDeathEvents,
+            PLAYER_SIZE,
+        },
+        resources::GameRng,
+        score::resources::Score,
+        star::resources::StarSpawnTimer,
+    },
+    loading::resources::GameAssets,
+    localization::{console::PrintLocalized, resources::LocalizedString},
+};
+
+fn phase_timers(phase: BossPhase) -> (Timer, Timer) {
+    match phase {
+        BossPhase::BulletRing => (
+            Timer::from_seconds(BOSS_PHASE_TIME, TimerMode::Once),
+            Timer::from_seconds(BULLET_RING_INTERVAL, TimerMode::Repeating),
+        ),
+        BossPhase::Summon => (
+            Timer::from_seconds(BOSS_PHASE_TIME, TimerMode::Once),
+            Timer::from_seconds(SUMMON_INTERVAL, TimerMode::Repeating),
+        ),
+        BossPhase::Vulnerable => (
+            Timer::from_seconds(BOSS_VULNERABLE_TIME, TimerMode::Once),
+            Timer::from_seconds(BOSS_VULNERABLE_TIME, TimerMode::Once),
+        ),
+    }
+}
+
+fn set_phase(boss: &mut Boss, phase: BossPhase) {
+    let (phase_timer, attack_timer) = phase_timers(phase);
+    boss.phase = phase;
+    boss.phase_timer = phase_timer;
+    boss.attack_timer = attack_timer;
+}
+
+pub fn spawn_boss(commands: &mut Commands, game_assets: &GameAssets, position: Vec2, boss: Boss) {
+    commands
+        .spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: BOSS_COLOR,
+                    custom_size: Some(Vec2::splat(BOSS_SIZE)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(position.x, position.y, 0.0),
+                texture: game_assets.enemy_texture.clone(),
+                ..default()
+            },
+            boss,
+        ))
+        .with_children(|parent| {
+            let bar_y = BOSS_SIZE / 2.0 + HEALTH_BAR_HEIGHT * 2.0;
+            // === Health Bar ===
+            parent.spawn(SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgb(0.15, 0.15, 0.15),
+                    custom_size: Some(Vec2::new(HEALTH_BAR_WIDTH, HEALTH_BAR_HEIGHT)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(0.0, bar_y, 0.1),
+                ..default()
+            });
+            parent.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: Color::rgb(0.9, 0.2, 0.2),
+                        custom_size: Some(Vec2::new(HEALTH_BAR_WIDTH, HEALTH_BAR_HEIGHT)),
+                        anchor: Anchor::CenterLeft,
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(-HEALTH_BAR_WIDTH / 2.0, bar_y, 0.2),
+                    ..default()
+                },
+                BossHealthBar {},
+            ));
+        });
+}
+
+pub fn spawn_bullet(
+    commands: &mut Commands,
+    game_assets: &GameAssets,
+    position: Vec2,
+    bullet: BossBullet,
+) {
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: BOSS_COLOR,
+                custom_size: Some(Vec2::splat(BULLET_SIZE)),
+                ..default()
+            },
+            transform: Transform::from_xyz(position.x, position.y, 0.0),
+            texture: game_assets.enemy_texture.clone(),
+            ..default()
+        },
+        bullet,
+    ));
+}
+
+pub fn reset_boss_schedule(
+    mut boss_schedule: ResMut<BossSchedule>,
+    mut boss_encounter: ResMut<BossEncounter>,
+) {
+    boss_schedule.next = 0;
+    boss_encounter.active = false;
+}
+
+pub fn start_boss_encounter(
+    mut commands: Commands,
+    mut boss_schedule: ResMut<BossSchedule>,
+    mut boss_encounter: ResMut<BossEncounter>,
+    mut camera_event_writer: EventWriter<CameraEvent>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    game_assets: Res<GameAssets>,
+    score: Res<Score>,
+) {
+    if boss_encounter.active {
+        return;
+    }
+    let Some(threshold) = boss_schedule.next_threshold() else {
+        return;
+    };
+    if score.value < threshold {
+        return;
+    }
+
+    let window = window_query.get_single().unwrap();
+    let anchor = Vec2::new(window.width() / 2.0, window.height() / 2.0);
+    let (phase_timer, attack_timer) = phase_timers(BossPhase::BulletRing);
+
+    spawn_boss(
+        &mut commands,
+        &game_assets,
+        anchor,
+        Boss {
+            health: BOSS_HEALTH,
+            max_health: BOSS_HEALTH,
+            phase: BossPhase::BulletRing,
+            phase_timer,
+            attack_timer,
+            encounter_timer: Timer::from_seconds(BOSS_ENCOUNTER_TIME, TimerMode::Once),
+            anchor,
+        },
+    );
+
+    boss_encounter.active = true;
+    boss_schedule.next += 1;
+    camera_event_writer.send(CameraEvent::Shake(0.5));
+    commands.add(PrintLocalized(
+        LocalizedString::new("console.boss_appeared").with_arg("score", score.value),
+    ));
+}
+
+pub fn boss_movement(mut boss_query: Query<(&mut Transform, &Boss)>) {
+    for (mut transform, boss) in boss_query.iter_mut() {
+        let t = boss.encounter_timer.elapsed_secs();
+        let offset = Vec2::new((t * 0.7).sin(), (t * 1.1).sin()) * BOSS_WANDER_RADIUS;
+        let position = boss.anchor + offset;
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+    }
+}
+
+pub fn update_boss_phase(mut boss_query: Query<(&mut Boss, &mut Sprite)>, time: Res<Time>) {
+    for (mut boss, mut sprite) in boss_query.iter_mut() {
+        boss.encounter_timer.tick(time.delta());
+        boss.phase_timer.tick(time.delta());
+
+        if boss.phase_timer.finished() {
+            let next_phase = boss.phase.next();
+            set_phase(&mut boss, next_phase);
+        }
+
+        sprite.color = match boss.phase {
+            BossPhase::Vulnerable => BOSS_VULNERABLE_COLOR,
+            _ => BOSS_COLOR,
+        };
+    }
+}
+
+pub fn boss_attacks(
+    mut commands: Commands,
+    mut boss_query: Query<(&Transform, &mut Boss)>,
+    game_assets: Res<GameAssets>,
+    mut game_rng: ResMut<GameRng>,
+    time: Res<Time>,
+) {
+    for (transform, mut boss) in boss_query.iter_mut() {
+        boss.attack_timer.tick(time.delta());
+        if !boss.attack_timer.just_finished() {
+            continue;
+        }
+
+        let position = transform.translation.truncate();
+        match boss.phase {
+            BossPhase::BulletRing => {
+                // Rotate successive rings so there are no safe lanes.
+                let offset = boss.phase_timer.elapsed_secs();
+                for i in 0..BULLETS_PER_RING {
+                    let angle = offset + i as f32 / BULLETS_PER_RING as f32 * TAU;
+                    spawn_bullet(
+                        &mut commands,
+                        &game_assets,
+                        position,
+                        BossBullet {
+                            velocity: Vec2::from_angle(angle) * BULLET_SPEED,
+                        },
+                    );
+                }
+            }
+            BossPhase::Summon => {
+                let bouncer = archetype(EnemyKind::Bouncer);
+                let direction = random_direction(&mut *game_rng);
+                spawn_enemy(
+                    &mut commands,
+                    &game_assets,
+                    &mut game_rng,
+                    bouncer,
+                    position,
+                    direction,
+                    bouncer.size,
+                );
+            }
+            BossPhase::Vulnerable => {}
+        }
+    }
+}
+
+pub fn bullet_movement(
+    mut commands: Commands,
+    mut bullet_query: Query<(Entity, &mut Transform, &BossBullet)>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    time: Res<Time>,
+) {
+    let window = window_query.get_single().unwrap();
+
+    for (bullet_entity, mut transform, bullet) in bullet_query.iter_mut() {
+        transform.translation += bullet.velocity.extend(0.0) * time.delta_seconds();
+
+        let translation = transform.translation;
+        if translation.x < -BULLET_SIZE
+            || translation.x > window.width() + BULLET_SIZE
+            || translation.y < -BULLET_SIZE
+            || translation.y > window.height() + BULLET_SIZE
+        {
+            commands.entity(bullet_entity).despawn();
+        }
+    }
+}
+
+pub fn update_boss_health_bar(
+    mut health_bar_query: Query<(&Parent, &mut Sprite), With<BossHealthBar>>,
+    boss_query: Query<&Boss>,
+) {
+    for (parent, mut sprite) in health_bar_query.iter_mut() {
+        if let Ok(boss) = boss_query.get(parent.get()) {
+            let fraction = boss.health as f32 / boss.max_health as f32;
+            sprite.custom_size = Some(Vec2::new(HEALTH_BAR_WIDTH * fraction, HEALTH_BAR_HEIGHT));
+        }
+    }
+}
+
+/// Touching the boss only matters while it is vulnerable, when it takes damage.
+pub fn player_hit_boss(
+    mut boss_query: Query<(&Transform, &mut Boss)>,
+    player_query: Query<&Transform, With<Player>>,
+    mut sound_event_writer: EventWriter<SoundEvent>,
+    mut particle_event_writer: EventWriter<ParticleEvent>,
+    mut camera_event_writer: EventWriter<CameraEvent>,
+) {
+    for (boss_transform, mut boss) in boss_query.iter_mut() {
+        if boss.phase != BossPhase::Vulnerable {
+            continue;
+        }
+
+        let touched = player_query.iter().any(|player_transform| {
+            let distance = player_transform
+                .translation
+                .distance(boss_transform.translation);
+            distance < PLAYER_SIZE / 2.0 + BOSS_SIZE / 2.0
+        });
+        if touched {
+            boss.health = boss.health.saturating_sub(1);
+            let next_phase = boss.phase.next();
+            set_phase(&mut boss, next_phase);
+
+            sound_event_writer.send(SoundEvent::BossHit);
+            particle_event_writer.send(ParticleEvent {
+                effect: ParticleEffect::StarPickup,
+                position: boss_transform.translation.truncate(),
+                direction: Vec2::ZERO,
+            });
+            camera_event_writer.send(CameraEvent::Shake(0.4));
+        }
+    }
+}
+
+pub fn player_hit_bullets(
+    mut commands: Commands,
+    player_query: Query<(Entity, &Player, &Transform), Without<Invulnerable>>,
+    bullet_query: Query<&Transform, With<BossBullet>>,
+    mut player_death_events: PlayerDeathEvents,
+) {
+    for (player_entity, player, player_transform) in player_query.iter() {
+        for bullet_transform in bullet_query.iter() {
+            let distance = player_transform
+                .translation
+                .distance(bullet_transform.translation);
+            if distance < PLAYER_SIZE / 2.0 + BULLET_SIZE / 2.0 {
+                commands.entity(player_entity).despawn();
+                player_death_events.send(
+                    player.id,
+                    player_transform.translation.truncate(),
+                    DeathCause::BossBullet,
+                );
+                break;
+            }
+        }
+    }
+}
+
+pub fn end_boss_encounter(
+    mut commands: Commands,
+    boss_query: Query<(Entity, &Boss)>,
+    bullet_query: Query<Entity, With<BossBullet>>,
+    mut boss_encounter: ResMut<BossEncounter>,
+    mut enemy_spawn_timer: ResMut<EnemySpawnTimer>,
+    mut star_spawn_timer: ResMut<StarSpawnTimer>,
+    mut score: ResMut<Score>,
+) {
+    for (boss_entity, boss) in boss_query.iter() {
+        let bonus = if boss.health == 0 {
+            commands.add(PrintLocalized(
+                LocalizedString::new("console.boss_defeated").with_arg("bonus", BOSS_DEFEAT_BONUS),
+            ));
+            BOSS_DEFEAT_BONUS
+        } else if boss.encounter_timer.finished() {
+            commands.add(PrintLocalized(
+                LocalizedString::new("console.boss_survived").with_arg("bonus", BOSS_SURVIVE_BONUS),
+            ));
+            BOSS_SURVIVE_BONUS
+        } else {
+            continue;
+        };
+
+        score.value += bonus;
+        commands.entity(boss_entity).despawn_recursive();
+        for bullet_entity in bullet_query.iter() {
+            commands.entity(bullet_entity).despawn();
+        }
+
+        boss_encounter.active = false;
+        // Resume regular spawning from a fresh interval.
+        enemy_spawn_timer.timer.reset();
+        star_spawn_timer.timer.reset();
+    }
+}
+
+pub fn despawn_boss(
+    mut commands: Commands,
+    boss_query: Query<Entity, With<Boss>>,
+    bullet_query: Query<Entity, With<BossBullet>>,
+    mut boss_encounter: ResMut<BossEncounter>,
+) {
+    for boss_entity in boss_query.iter() {
+        commands.entity(boss_entity).despawn_recursive();
+    }
+    for bullet_entity in bullet_query.iter() {
+        commands.entity(bullet_entity).despawn();
+    }
+    boss_encounter.active = false;
+}
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+
+use crate::AppState;
+
+use self::{
+    resources::{CampaignProgress, CampaignRun},
+    systems::*,
+};
+
+use super::SimulationState;
+
+pub mod resources;
+pub mod stages;
+pub mod starter;
+mod systems;
+
+const CAMPAIGN_FILE: &str = "campaign.ron";
+
+pub struct CampaignPlugin;
+
+impl Plugin for CampaignPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CampaignProgress>()
+            .add_systems(
+                OnEnter(AppState::InGame),
+                reset_campaign_run.run_if(resource_exists::<CampaignRun>()),
+            )
+            .add_systems(
+                Update,
+                (count_campaign_stars, track_objective)
+                    .chain()
+                    .run_if(in_state(AppState::InGame))
+                    .run_if(in_state(SimulationState::Running))
+                    .run_if(resource_exists::<CampaignRun>()),
+            )
+            .add_systems(OnEnter(AppState::MainMenu), end_campaign_run)
+            .add_systems(OnEnter(AppState::Campaign), end_campaign_run);
+    }
+}
@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{stages::STAGES, CAMPAIGN_FILE};
+use crate::{
+    profile::resources::ActiveProfile,
+    save::files::{read_ron, write_ron},
+};
+
+/// Present while playing a campaign stage, including on the screens between stages.
+#[derive(Resource)]
+pub struct CampaignRun {
+    pub stage: usize,
+    pub elapsed: f32,
+    /// Counted on pickup rather than read off the score, which bonuses also add to.
+    pub stars: u32,
+}
+
+/// How the last cleared stage went, for `AppState::LevelComplete`.
+#[derive(Resource)]
+pub struct StageResult {
+    pub stage: usize,
+    pub rating: u8,
+    pub time: f32,
+    pub score: u32,
+}
+
+#[derive(Resource, Serialize, Deserialize)]
+pub struct CampaignProgress {
+    /// Stages before this index can be played.
+    pub unlocked: usize,
+    /// Best rating per stage, zero if not cleared yet.
+    pub ratings: Vec<u8>,
+}
+
+impl Default for CampaignProgress {
+    fn default() -> Self {
+        CampaignProgress {
+            unlocked: 1,
+            ratings: vec![0; STAGES.len()],
+        }
+    }
+}
+
+impl CampaignProgress {
+    pub fn load(profile: &ActiveProfile) -> Self {
+        let mut progress: CampaignProgress =
+            read_ron(&profile.path(CAMPAIGN_FILE)).unwrap_or_default();
+        // Stages may have been added since the file was written.
+        progress.ratings.resize(STAGES.len(), 0);
+        progress.unlocked = progress.unlocked.clamp(1, STAGES.len());
+        progress
+    }
+
+    pub fn save(&self, profile: &ActiveProfile) {
+        write_ron(&profile.path(CAMPAIGN_FILE), self);
+    }
+
+    pub fn record(&mut self, stage: usize, rating: u8) {
+        self.ratings[stage] = self.ratings[stage].max(rating);
+        self.unlocked = self.unlocked.max(stage + 2).min(STAGES.len());
+    }
+}
@@ -0,0 +1,161 @@
+use crate::localization::resources::LocalizedString;
+
+/// What has to be done to clear a stage, along with what earns a better rating.
+pub enum Objective {
+    /// Collect `stars` stars, the quicker the better.
+    CollectStars { stars: u32, par_times: [f32; 2] },
+    /// Stay alive for `seconds`, collecting as many stars as possible on the way.
+    Survive { seconds: f32, par_stars: [u32; 2] },
+    /// Reach `score` before `time_limit` runs out, the quicker the better.
+    ReachScore {
+        score: u32,
+        time_limit: f32,
+        par_times: [f32; 2],
+    },
+}
+
+/// Three stars for beating the first par, two for the second.
+fn rate_time(elapsed: f32, par_times: [f32; 2]) -> u8 {
+    if elapsed <= par_times[0] {
+        3
+    } else if elapsed <= par_times[1] {
+        2
+    } else {
+        1
+    }
+}
+
+impl Objective {
+    pub fn complete(&self, elapsed: f32, stars: u32, score: u32) -> bool {
+        match *self {
+            Objective::CollectStars { stars: target, .. } => stars >= target,
+            Objective::Survive { seconds, .. } => elapsed >= seconds,
+            Objective::ReachScore { score: target, .. } => score >= target,
+        }
+    }
+
+    pub fn failed(&self, elapsed: f32) -> bool {
+        match *self {
+            Objective::ReachScore { time_limit, .. } => elapsed >= time_limit,
+            Objective::CollectStars { .. } | Objective::Survive { .. } => false,
+        }
+    }
+
+    /// From one to three stars.
+    pub fn rating(&self, elapsed: f32, stars: u32) -> u8 {
+        match *self {
+            Objective::CollectStars { par_times, .. } | Objective::ReachScore { par_times, .. } => {
+                rate_time(elapsed, par_times)
+            }
+            Objective::Survive { par_stars, .. } => {
+                if stars >= par_stars[0] {
+                    3
+                } else if stars >= par_stars[1] {
+                    2
+                } else {
+                    1
+                }
+            }
+        }
+    }
+
+    pub fn description(&self) -> LocalizedString {
+        match *self {
+            Objective::CollectStars { stars, .. } => {
+                LocalizedString::new("objective.collect_stars").with_arg("stars", stars)
+            }
+            Objective::Survive { seconds, .. } => LocalizedString::new("objective.survive")
+                .with_arg("seconds", format!("{seconds:.0}")),
+            Objective::ReachScore {
+                score, time_limit, ..
+            } => LocalizedString::new("objective.reach_score")
+                .with_arg("score", score)
+                .with_arg("time_limit", format!("{time_limit:.0}")),
+        }
+    }
+
+    pub fn progress(&self, elapsed: f32, stars: u32, score: u32) -> LocalizedString {
+        match *self {
+            Objective::CollectStars { stars: target, .. } => {
+                LocalizedString::new("objective.collect_stars.progress")
+                    .with_arg("stars", stars)
+                    .with_arg("target", target)
+            }
+            Objective::Survive { seconds, .. } => {
+                LocalizedString::new("objective.survive.progress")
+                    .with_arg("elapsed", format!("{elapsed:.0}"))
+                    .with_arg("seconds", format!("{seconds:.0}"))
+            }
+            Objective::ReachScore {
+                score: target,
+                time_limit,
+                ..
+            } => LocalizedString::new("objective.reach_score.progress")
+                .with_arg("score", score)
+                .with_arg("target", target)
+                .with_arg(
+                    "remaining",
+                    format!("{:.0}", (time_limit - elapsed).max(0.0)),
+                ),
+        }
+    }
+}
+
+pub struct CampaignStage {
+    /// String table key.
+    pub name: &'static str,
+    /// Index into `GameAssets::levels`; `None` plays the open arena.
+    pub level: Option<usize>,
+    pub objective: Objective,
+}
+
+pub const STAGES: [CampaignStage; 5] = [
+    CampaignStage {
+        name: "stage.first_steps",
+        level: None,
+        objective: Objective::CollectStars {
+            stars: 5,
+            par_times: [15.0, 30.0],
+        },
+    },
+    CampaignStage {
+        name: "stage.hold_on",
+        level: None,
+        objective: Objective::Survive {
+            seconds: 30.0,
+            par_stars: [12, 6],
+        },
+    },
+    CampaignStage {
+        name: "stage.pillars",
+        level: Some(0),
+        objective: Objective::CollectStars {
+            stars: 10,
+            par_times: [30.0, 50.0],
+        },
+    },
+    CampaignStage {
+        name: "stage.crossroads",
+        level: Some(1),
+        objective: Objective::ReachScore {
+            score: 15,
+            time_limit: 60.0,
+            par_times: [30.0, 45.0],
+        },
+    },
+    CampaignStage {
+        name: "stage.endurance",
+        level: Some(0),
+        objective: Objective::Survive {
+            seconds: 90.0,
+            par_stars: [40, 25],
+        },
+    },
+];
+
+/// Rating as text, since the font has no star glyph.
+pub fn rating_label(rating: u8) -> String {
+    let earned = "*".repeat(rating as usize);
+    let missing = "-".repeat(3 - rating.min(3) as usize);
+    format!("[{earned}{missing}]")
+}
@@ -0,0 +1,35 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use super::{resources::CampaignRun, stages::STAGES};
+use crate::{
+    game::{
+        level::resources::SelectedLevel, mode::resources::GameMode, player::resources::PlayerMode,
+    },
+    AppState,
+};
+
+/// Everything needed to kick off a campaign stage from a menu.
+#[derive(SystemParam)]
+pub struct StageStarter<'w, 's> {
+    commands: Commands<'w, 's>,
+    selected_level: ResMut<'w, SelectedLevel>,
+    player_mode: ResMut<'w, PlayerMode>,
+    game_mode: ResMut<'w, GameMode>,
+    next_app_state: ResMut<'w, NextState<AppState>>,
+}
+
+impl<'w, 's> StageStarter<'w, 's> {
+    /// The stage's level stays selected afterwards, like picking it from the level select.
+    pub fn start(&mut self, stage: usize) {
+        self.commands.insert_resource(CampaignRun {
+            stage,
+            elapsed: 0.0,
+            stars: 0,
+        });
+        self.selected_level.level = STAGES[stage].level;
+        *self.player_mode = PlayerMode::Single;
+        // Stages have their own objectives and time limits.
+        *self.game_mode = GameMode::Endless;
+        self.next_app_state.set(AppState::InGame);
+    }
+}
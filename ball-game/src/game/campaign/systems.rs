@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+
+use super::{
+    resources::{CampaignProgress, CampaignRun, StageResult},
+    stages::STAGES,
+};
+use crate::{
+    events::{GameOver, StarCollected},
+    game::score::resources::Score,
+    localization::{console::PrintLocalized, resources::LocalizedString},
+    AppState,
+};
+
+/// Restarting a stage with G starts its clock and star count over.
+pub fn reset_campaign_run(mut campaign_run: ResMut<CampaignRun>) {
+    campaign_run.elapsed = 0.0;
+    campaign_run.stars = 0;
+}
+
+pub fn count_campaign_stars(
+    mut star_collected_event_reader: EventReader<StarCollected>,
+    mut campaign_run: ResMut<CampaignRun>,
+) {
+    for _ in star_collected_event_reader.iter() {
+        campaign_run.stars += 1;
+    }
+}
+
+pub fn track_objective(
+    mut commands: Commands,
+    mut campaign_run: ResMut<CampaignRun>,
+    mut campaign_progress: ResMut<CampaignProgress>,
+    mut game_over_event_writer: EventWriter<GameOver>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    score: Res<Score>,
+    time: Res<Time>,
+) {
+    campaign_run.elapsed += time.delta_seconds();
+
+    let stage = campaign_run.stage;
+    let objective = &STAGES[stage].objective;
+    let stars = campaign_run.stars;
+
+    if objective.complete(campaign_run.elapsed, stars, score.value) {
+        let rating = objective.rating(campaign_run.elapsed, stars);
+        campaign_progress.record(stage, rating);
+
+        commands.insert_resource(StageResult {
+            stage,
+            rating,
+            time: campaign_run.elapsed,
+            score: score.value,
+        });
+        next_app_state.set(AppState::LevelComplete);
+    } else if objective.failed(campaign_run.elapsed) {
+        commands.add(PrintLocalized(LocalizedString::new("console.out_of_time")));
+        game_over_event_writer.send(GameOver {
+            score: score.value,
+            winner: None,
+        });
+    }
+}
+
+pub fn end_campaign_run(mut commands: Commands) {
+    commands.remove_resource::<CampaignRun>();
+    commands.remove_resource::<StageResult>();
+}
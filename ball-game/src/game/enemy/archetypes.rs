@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+
+use super::{components::EnemyKind, direction::SpeedRange, ENEMY_SIZE};
+
+pub enum Behaviour {
+    Bounce,
+    Homing {
+        turn_rate: f32,
+    },
+    SineWave {
+        amplitude: f32,
+        frequency: f32,
+    },
+    Split {
+        bounces: u32,
+        /// How many times the children can split again.
+        generations: u32,
+    },
+    Dash {
+        interval: f32,
+        duration: f32,
+        speed_multiplier: f32,
+    },
+}
+
+/// Data describing an enemy type.
+pub struct EnemyArchetype {
+    pub kind: EnemyKind,
+    /// Relative chance of being picked by `spawn_enemies_over_time`.
+    pub weight: u32,
+    pub speed: SpeedRange,
+    pub size: f32,
+    pub color: Color,
+    pub behaviour: Behaviour,
+}
+
+pub const ARCHETYPES: [EnemyArchetype; 5] = [
+    EnemyArchetype {
+        kind: EnemyKind::Bouncer,
+        weight: 6,
+        speed: SpeedRange::new(170.0, 230.0),
+        size: ENEMY_SIZE,
+        color: Color::WHITE,
+        behaviour: Behaviour::Bounce,
+    },
+    EnemyArchetype {
+        kind: EnemyKind::Chaser,
+        weight: 2,
+        speed: SpeedRange::new(120.0, 160.0),
+        size: 48.0,
+        color: Color::rgb(1.0, 0.6, 0.6),
+        behaviour: Behaviour::Homing { turn_rate: 1.5 },
+    },
+    EnemyArchetype {
+        kind: EnemyKind::Drifter,
+        weight: 3,
+        speed: SpeedRange::new(140.0, 180.0),
+        size: ENEMY_SIZE,
+        color: Color::rgb(0.8, 0.6, 1.0),
+        behaviour: Behaviour::SineWave {
+            amplitude: 80.0,
+            frequency: 3.0,
+        },
+    },
+    EnemyArchetype {
+        kind: EnemyKind::Splitter,
+        weight: 2,
+        speed: SpeedRange::new(160.0, 220.0),
+        size: 80.0,
+        color: Color::rgb(0.6, 1.0, 0.6),
+        behaviour: Behaviour::Split {
+            bounces: 3,
+            generations: 1,
+        },
+    },
+    EnemyArchetype {
+        kind: EnemyKind::Dasher,
+        weight: 2,
+        speed: SpeedRange::fixed(120.0),
+        size: 56.0,
+        color: Color::rgb(1.0, 0.9, 0.5),
+        behaviour: Behaviour::Dash {
+            interval: 2.5,
+            duration: 0.35,
+            speed_multiplier: 4.0,
+        },
+    },
+];
+
+pub fn archetype(kind: EnemyKind) -> &'static EnemyArchetype {
+    ARCHETYPES
+        .iter()
+        .find(|archetype| archetype.kind == kind)
+        .unwrap()
+}
@@ -1,6 +1,56 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Component)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EnemyKind {
+    Bouncer,
+    Chaser,
+    Drifter,
+    Splitter,
+    Dasher,
+}
+
+#[derive(Component, Clone, Serialize, Deserialize)]
 pub struct Enemy {
+    pub kind: EnemyKind,
     pub direction: Vec2,
+    pub speed: f32,
+    pub size: f32,
+}
+
+/// Steers the enemy towards the player.
+#[derive(Component, Clone, Serialize, Deserialize)]
+pub struct Homing {
+    /// Maximum turn rate, in radians per second.
+    pub turn_rate: f32,
+}
+
+/// Wobbles the enemy sideways around its direction of travel.
+#[derive(Component, Clone, Serialize, Deserialize)]
+pub struct SineWave {
+    pub amplitude: f32,
+    pub frequency: f32,
+    pub phase: f32,
+}
+
+/// Breaks the enemy into two smaller enemies once it runs out of bounces.
+#[derive(Component, Clone, Serialize, Deserialize)]
+pub struct Splitter {
+    pub bounces_left: u32,
+    /// How many more times the children will split.
+    pub generations_left: u32,
+}
+
+/// Periodically lunges forward at a multiple of the enemy's speed.
+#[derive(Component, Clone, Serialize, Deserialize)]
+pub struct Dasher {
+    pub interval: Timer,
+    pub dash: Timer,
+    pub speed_multiplier: f32,
+}
+
+impl Dasher {
+    pub fn dashing(&self) -> bool {
+        !self.dash.finished()
+    }
 }
@@ -0,0 +1,119 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use rand::Rng;
+
+/// Inclusive range an enemy's speed is drawn from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpeedRange {
+    pub min: f32,
+    pub max: f32,
+}
+
+impl SpeedRange {
+    pub const fn new(min: f32, max: f32) -> Self {
+        SpeedRange { min, max }
+    }
+
+    pub const fn fixed(speed: f32) -> Self {
+        SpeedRange::new(speed, speed)
+    }
+
+    /// Draws a speed uniformly from the range; an empty or inverted range yields `min`.
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f32 {
+        if self.max > self.min {
+            rng.gen_range(self.min..=self.max)
+        } else {
+            self.min
+        }
+    }
+}
+
+/// Draws a unit vector uniformly over the full circle.
+pub fn random_direction<R: Rng + ?Sized>(rng: &mut R) -> Vec2 {
+    Vec2::from_angle(rng.gen_range(0.0..TAU))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    const SAMPLES: usize = 10_000;
+
+    fn quadrant(direction: Vec2) -> usize {
+        match (direction.x >= 0.0, direction.y >= 0.0) {
+            (true, true) => 0,
+            (false, true) => 1,
+            (false, false) => 2,
+            (true, false) => 3,
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn directions_are_finite_unit_vectors(seed: u64) {
+            let mut rng = StdRng::seed_from_u64(seed);
+            for _ in 0..SAMPLES {
+                let direction = random_direction(&mut rng);
+                prop_assert!(direction.is_finite(), "{direction:?} is not finite");
+                prop_assert!((direction.length() - 1.0).abs() < 1e-5, "{direction:?}");
+            }
+        }
+
+        #[test]
+        fn directions_reach_every_quadrant_evenly(seed: u64) {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut quadrants = [0usize; 4];
+            let mut sum = Vec2::ZERO;
+            for _ in 0..SAMPLES {
+                let direction = random_direction(&mut rng);
+                quadrants[quadrant(direction)] += 1;
+                sum += direction;
+            }
+
+            for count in quadrants {
+                let share = count as f32 / SAMPLES as f32;
+                prop_assert!((share - 0.25).abs() < 0.03, "quadrant shares {quadrants:?}");
+            }
+            // Uniform directions cancel out on average.
+            prop_assert!((sum / SAMPLES as f32).length() < 0.05, "mean {sum:?}");
+        }
+
+        #[test]
+        fn speeds_stay_within_range(
+            seed: u64,
+            min in 0.0f32..1_000.0,
+            width in 0.0f32..500.0,
+        ) {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let range = SpeedRange::new(min, min + width);
+            for _ in 0..SAMPLES / 10 {
+                let speed = range.sample(&mut rng);
+                prop_assert!(
+                    (range.min..=range.max).contains(&speed),
+                    "{speed} outside {range:?}"
+                );
+            }
+        }
+
+        #[test]
+        fn fixed_speeds_yield_that_speed(seed: u64, speed in 0.0f32..1_000.0) {
+            let mut rng = StdRng::seed_from_u64(seed);
+            prop_assert_eq!(SpeedRange::fixed(speed).sample(&mut rng), speed);
+            prop_assert_eq!(SpeedRange::new(speed, speed).sample(&mut rng), speed);
+        }
+
+        #[test]
+        fn inverted_speed_ranges_yield_min(
+            seed: u64,
+            max in 0.0f32..1_000.0,
+            width in 0.001f32..500.0,
+        ) {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let range = SpeedRange::new(max + width, max);
+            prop_assert_eq!(range.sample(&mut rng), range.min);
+        }
+    }
+}
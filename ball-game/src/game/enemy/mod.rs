@@ -1,36 +1,56 @@
+pub mod archetypes;
 pub mod components;
+pub mod direction;
 pub mod resources;
 mod systems;
 use bevy::prelude::*;
 
-use crate::AppState;
+use crate::{save::resources::ResumeRun, AppState};
 
 use self::{resources::EnemySpawnTimer, systems::*};
 
-use super::SimulationState;
+pub use systems::{enemy_sprite_bundle, spawn_enemy};
+
+use super::{boss::resources::no_boss_encounter, systems::seed_game_rng, SimulationState};
 
 const NUMBER_OF_ENEMIES: usize = 4;
-const ENEMY_SPEED: f32 = 200.0;
 pub const ENEMY_SIZE: f32 = 64.0;
+/// Size of a split enemy relative to its parent.
+const SPLIT_SCALE: f32 = 0.6;
+/// Angle (in radians) each split child deviates from the parent's direction.
+const SPLIT_ANGLE: f32 = std::f32::consts::FRAC_PI_4;
 
 pub struct EnemyPlugin;
 
 impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<EnemySpawnTimer>()
-            .add_systems(OnEnter(AppState::InGame), spawn_enemies)
+            .add_systems(
+                OnEnter(AppState::InGame),
+                spawn_enemies
+                    .after(seed_game_rng)
+                    .run_if(not(resource_exists::<ResumeRun>())),
+            )
             .add_systems(
                 Update,
                 (
+                    steer_homing_enemies,
+                    tick_dashers,
                     enemy_movement,
                     update_enemy_direction,
                     confine_enemy_movement,
-                    tick_enemy_spawn_timer,
-                    spawn_enemies_over_time,
+                    split_enemies,
                 )
                     .run_if(in_state(AppState::InGame))
                     .run_if(in_state(SimulationState::Running)),
             )
+            .add_systems(
+                Update,
+                (tick_enemy_spawn_timer, spawn_enemies_over_time)
+                    .run_if(in_state(AppState::InGame))
+                    .run_if(in_state(SimulationState::Running))
+                    .run_if(no_boss_encounter),
+            )
             .add_systems(OnExit(AppState::InGame), despawn_enemies);
     }
 }
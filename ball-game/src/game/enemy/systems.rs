@@ -1,31 +1,158 @@
+use std::{f32::consts::TAU, time::Duration};
+
 use super::{
-    components::Enemy, resources::EnemySpawnTimer, ENEMY_SIZE, ENEMY_SPEED, NUMBER_OF_ENEMIES,
+    archetypes::{archetype, Behaviour, EnemyArchetype, ARCHETYPES},
+    components::{Dasher, Enemy, EnemyKind, Homing, SineWave, Splitter},
+    direction::random_direction,
+    resources::EnemySpawnTimer,
+    NUMBER_OF_ENEMIES, SPLIT_ANGLE, SPLIT_SCALE,
+};
+use crate::{
+    events::{EnemyBounced, ParticleEvent, SoundEvent},
+    game::{
+        level::active::{to_world, ActiveLevel, SpawnZone},
+        particle::components::ParticleEffect,
+        player::components::Player,
+        resources::GameRng,
+    },
+    loading::resources::GameAssets,
+    systems::get_bounds,
 };
-use crate::systems::get_bounds;
 use bevy::{prelude::*, window::PrimaryWindow};
-use rand::random;
+use rand::{
+    distributions::{Distribution, WeightedIndex},
+    Rng,
+};
+
+pub fn enemy_sprite_bundle(
+    game_assets: &GameAssets,
+    archetype: &EnemyArchetype,
+    position: Vec2,
+    size: f32,
+) -> SpriteBundle {
+    SpriteBundle {
+        sprite: Sprite {
+            color: archetype.color,
+            custom_size: Some(Vec2::splat(size)),
+            ..default()
+        },
+        transform: Transform::from_xyz(position.x, position.y, 0.0),
+        texture: game_assets.enemy_texture.clone(),
+        ..default()
+    }
+}
+
+pub fn spawn_enemy(
+    commands: &mut Commands,
+    game_assets: &GameAssets,
+    rng: &mut GameRng,
+    archetype: &EnemyArchetype,
+    position: Vec2,
+    direction: Vec2,
+    size: f32,
+) -> Entity {
+    let mut enemy_commands = commands.spawn((
+        enemy_sprite_bundle(game_assets, archetype, position, size),
+        Enemy {
+            kind: archetype.kind,
+            direction,
+            speed: archetype.speed.sample(rng),
+            size,
+        },
+    ));
+
+    match archetype.behaviour {
+        Behaviour::Bounce => {}
+        Behaviour::Homing { turn_rate } => {
+            enemy_commands.insert(Homing { turn_rate });
+        }
+        Behaviour::SineWave {
+            amplitude,
+            frequency,
+        } => {
+            enemy_commands.insert(SineWave {
+                amplitude,
+                frequency,
+                phase: rng.gen_range(0.0..TAU),
+            });
+        }
+        Behaviour::Split {
+            bounces,
+            generations,
+        } => {
+            enemy_commands.insert(Splitter {
+                bounces_left: bounces,
+                generations_left: generations,
+            });
+        }
+        Behaviour::Dash {
+            interval,
+            duration,
+            speed_multiplier,
+        } => {
+            let mut dash = Timer::from_seconds(duration, TimerMode::Once);
+            dash.tick(Duration::from_secs_f32(duration));
+            enemy_commands.insert(Dasher {
+                interval: Timer::from_seconds(interval, TimerMode::Repeating),
+                dash,
+                speed_multiplier,
+            });
+        }
+    }
+
+    enemy_commands.id()
+}
+
+fn choose_archetype(rng: &mut GameRng) -> &'static EnemyArchetype {
+    let weights = WeightedIndex::new(ARCHETYPES.iter().map(|archetype| archetype.weight)).unwrap();
+    &ARCHETYPES[weights.sample(rng)]
+}
 
 pub fn spawn_enemies(
     mut commands: Commands,
     window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
+    game_assets: Res<GameAssets>,
+    mut game_rng: ResMut<GameRng>,
+    active_level: ActiveLevel,
 ) {
     let window = window_query.get_single().unwrap();
 
+    if let Some(level) = active_level.get() {
+        for placement in level.enemies.iter() {
+            let archetype = archetype(placement.kind);
+            let direction = match placement.direction {
+                Some(direction) => direction.normalize_or_zero(),
+                None => random_direction(&mut *game_rng),
+            };
+
+            spawn_enemy(
+                &mut commands,
+                &game_assets,
+                &mut game_rng,
+                archetype,
+                to_world(placement.position, window),
+                direction,
+                archetype.size,
+            );
+        }
+        return;
+    }
+
+    let bouncer = archetype(EnemyKind::Bouncer);
     for _ in 0..NUMBER_OF_ENEMIES {
-        let random_x = random::<f32>() * window.width();
-        let random_y = random::<f32>() * window.height();
-
-        commands.spawn((
-            SpriteBundle {
-                transform: Transform::from_xyz(random_x, random_y, 0.0),
-                texture: asset_server.load("sprites/ball_red_large.png"),
-                ..default()
-            },
-            Enemy {
-                direction: Vec2::new(random::<f32>(), random::<f32>()).normalize(),
-            },
-        ));
+        let random_x = game_rng.gen::<f32>() * window.width();
+        let random_y = game_rng.gen::<f32>() * window.height();
+        let direction = random_direction(&mut *game_rng);
+
+        spawn_enemy(
+            &mut commands,
+            &game_assets,
+            &mut game_rng,
+            bouncer,
+            Vec2::new(random_x, random_y),
+            direction,
+            bouncer.size,
+        );
     }
 }
 
@@ -35,60 +162,181 @@ pub fn despawn_enemies(mut commands: Commands, enemy_query: Query<Entity, With<E
     }
 }
 
-pub fn enemy_movement(mut enemy_query: Query<(&mut Transform, &Enemy)>, time: Res<Time>) {
-    for (mut transform, enemy) in enemy_query.iter_mut() {
-        let direction = Vec3::new(enemy.direction.x, enemy.direction.y, 0.0);
-        transform.translation += direction * ENEMY_SPEED * time.delta_seconds();
+pub fn enemy_movement(
+    mut enemy_query: Query<(
+        &mut Transform,
+        &Enemy,
+        Option<&mut SineWave>,
+        Option<&Dasher>,
+    )>,
+    time: Res<Time>,
+) {
+    for (mut transform, enemy, sine_wave, dasher) in enemy_query.iter_mut() {
+        let mut speed = enemy.speed;
+        if let Some(dasher) = dasher {
+            if dasher.dashing() {
+                speed *= dasher.speed_multiplier;
+            }
+        }
+
+        let mut velocity = enemy.direction * speed;
+        if let Some(mut sine_wave) = sine_wave {
+            // Derivative of a sideways `amplitude * sin(phase)` offset, with the phase advancing
+            // at `frequency` so the wave can be saved and picked up again.
+            sine_wave.phase += sine_wave.frequency * time.delta_seconds();
+            let sideways = Vec2::new(-enemy.direction.y, enemy.direction.x);
+            velocity +=
+                sideways * sine_wave.amplitude * sine_wave.frequency * sine_wave.phase.cos();
+        }
+
+        transform.translation += velocity.extend(0.0) * time.delta_seconds();
+    }
+}
+
+/// Offset from `position` to the closest player, if any are alive.
+fn to_nearest_player(
+    position: Vec3,
+    player_query: &Query<&Transform, With<Player>>,
+) -> Option<Vec2> {
+    player_query
+        .iter()
+        .map(|player_transform| (player_transform.translation - position).truncate())
+        .min_by(|a, b| a.length_squared().total_cmp(&b.length_squared()))
+}
+
+pub fn steer_homing_enemies(
+    mut enemy_query: Query<(&Transform, &mut Enemy, &Homing)>,
+    player_query: Query<&Transform, With<Player>>,
+    time: Res<Time>,
+) {
+    for (transform, mut enemy, homing) in enemy_query.iter_mut() {
+        let Some(to_player) = to_nearest_player(transform.translation, &player_query) else {
+            continue;
+        };
+        if to_player.length_squared() == 0.0 {
+            continue;
+        }
+
+        let max_turn = homing.turn_rate * time.delta_seconds();
+        let angle = enemy
+            .direction
+            .angle_between(to_player)
+            .clamp(-max_turn, max_turn);
+        enemy.direction = Vec2::from_angle(angle).rotate(enemy.direction);
+    }
+}
+
+pub fn tick_dashers(
+    mut enemy_query: Query<(&Transform, &mut Enemy, &mut Dasher)>,
+    player_query: Query<&Transform, With<Player>>,
+    time: Res<Time>,
+) {
+    for (transform, mut enemy, mut dasher) in enemy_query.iter_mut() {
+        dasher.dash.tick(time.delta());
+        dasher.interval.tick(time.delta());
+
+        if dasher.interval.just_finished() {
+            dasher.dash.reset();
+            // Lunge at the nearest player when there is one to lunge at.
+            if let Some(to_player) = to_nearest_player(transform.translation, &player_query) {
+                if to_player.length_squared() > 0.0 {
+                    enemy.direction = to_player.normalize();
+                }
+            }
+        }
     }
 }
 
 pub fn update_enemy_direction(
-    mut enemy_query: Query<(&Transform, &mut Enemy)>,
+    mut enemy_query: Query<(&Transform, &mut Enemy, Option<&mut Splitter>)>,
     window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
-    mut commands: Commands,
+    mut enemy_bounced_event_writer: EventWriter<EnemyBounced>,
+    mut sound_event_writer: EventWriter<SoundEvent>,
+    mut particle_event_writer: EventWriter<ParticleEvent>,
 ) {
     let window = window_query.get_single().unwrap();
 
-    let (x_min, x_max, y_min, y_max) = get_bounds(window, ENEMY_SIZE);
+    for (transform, mut enemy, splitter) in enemy_query.iter_mut() {
+        let (x_min, x_max, y_min, y_max) = get_bounds(window, enemy.size);
 
-    for (transform, mut enemy) in enemy_query.iter_mut() {
-        let mut direction_changed = false;
+        // Normal of the wall(s) the enemy bounced off, zero if it didn't bounce.
+        let mut wall_normal = Vec2::ZERO;
 
         let translation = transform.translation;
         if translation.x < x_min || translation.x > x_max {
             enemy.direction.x *= -1.0;
-            direction_changed = true;
+            wall_normal.x = if translation.x < x_min { 1.0 } else { -1.0 };
         }
         if translation.y < y_min || translation.y > y_max {
             enemy.direction.y *= -1.0;
-            direction_changed = true;
+            wall_normal.y = if translation.y < y_min { 1.0 } else { -1.0 };
         }
 
-        if direction_changed {
-            let sound_effect_1 = asset_server.load("audio/pluck_001.ogg");
-            let sound_effect_2 = asset_server.load("audio/pluck_002.ogg");
+        if wall_normal != Vec2::ZERO {
+            if let Some(mut splitter) = splitter {
+                splitter.bounces_left = splitter.bounces_left.saturating_sub(1);
+            }
 
-            let sound_effect = if random::<f32>() > 0.5 {
-                sound_effect_1
-            } else {
-                sound_effect_2
-            };
-            commands.spawn(AudioBundle {
-                source: sound_effect,
-                ..default()
+            enemy_bounced_event_writer.send(EnemyBounced);
+            sound_event_writer.send(SoundEvent::EnemyBounce);
+            particle_event_writer.send(ParticleEvent {
+                effect: ParticleEffect::WallSpark,
+                position: translation.truncate() - wall_normal * enemy.size / 2.0,
+                direction: wall_normal,
             });
         }
     }
 }
 
+pub fn split_enemies(
+    mut commands: Commands,
+    enemy_query: Query<(Entity, &Transform, &Enemy, &Splitter)>,
+    game_assets: Res<GameAssets>,
+    mut game_rng: ResMut<GameRng>,
+) {
+    for (enemy_entity, transform, enemy, splitter) in enemy_query.iter() {
+        if splitter.bounces_left > 0 || splitter.generations_left == 0 {
+            continue;
+        }
+
+        let archetype = archetype(enemy.kind);
+        let Behaviour::Split { bounces, .. } = archetype.behaviour else {
+            continue;
+        };
+
+        commands.entity(enemy_entity).despawn();
+
+        let generations_left = splitter.generations_left - 1;
+        for angle in [SPLIT_ANGLE, -SPLIT_ANGLE] {
+            let child_entity = spawn_enemy(
+                &mut commands,
+                &game_assets,
+                &mut game_rng,
+                archetype,
+                transform.translation.truncate(),
+                Vec2::from_angle(angle).rotate(enemy.direction),
+                enemy.size * SPLIT_SCALE,
+            );
+            // The last generation just bounces around.
+            if generations_left > 0 {
+                commands.entity(child_entity).insert(Splitter {
+                    bounces_left: bounces,
+                    generations_left,
+                });
+            } else {
+                commands.entity(child_entity).remove::<Splitter>();
+            }
+        }
+    }
+}
+
 pub fn confine_enemy_movement(
-    mut enemy_query: Query<&mut Transform, With<Enemy>>,
+    mut enemy_query: Query<(&mut Transform, &Enemy)>,
     window_query: Query<&Window, With<PrimaryWindow>>,
 ) {
     let window = window_query.get_single().unwrap();
-    let (x_min, x_max, y_min, y_max) = get_bounds(window, ENEMY_SIZE);
-    for mut transform in enemy_query.iter_mut() {
+    for (mut transform, enemy) in enemy_query.iter_mut() {
+        let (x_min, x_max, y_min, y_max) = get_bounds(window, enemy.size);
         let mut translation = transform.translation;
 
         if translation.x < x_min {
@@ -114,23 +362,25 @@ pub fn tick_enemy_spawn_timer(mut enemy_spawn_timer: ResMut<EnemySpawnTimer>, ti
 pub fn spawn_enemies_over_time(
     mut commands: Commands,
     window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
+    game_assets: Res<GameAssets>,
     enemy_spawn_timer: Res<EnemySpawnTimer>,
+    mut game_rng: ResMut<GameRng>,
+    active_level: ActiveLevel,
 ) {
     if enemy_spawn_timer.timer.finished() {
         let window = window_query.get_single().unwrap();
-        let random_x = random::<f32>() * window.width();
-        let random_y = random::<f32>() * window.height();
-
-        commands.spawn((
-            SpriteBundle {
-                transform: Transform::from_xyz(random_x, random_y, 0.0),
-                texture: asset_server.load("sprites/ball_red_large.png"),
-                ..default()
-            },
-            Enemy {
-                direction: Vec2::new(random::<f32>(), random::<f32>()).normalize(),
-            },
-        ));
+        let position = active_level.spawn_point(SpawnZone::Enemies, window, &mut game_rng);
+        let archetype = choose_archetype(&mut game_rng);
+        let direction = random_direction(&mut *game_rng);
+
+        spawn_enemy(
+            &mut commands,
+            &game_assets,
+            &mut game_rng,
+            archetype,
+            position,
+            direction,
+            archetype.size,
+        );
     }
 }
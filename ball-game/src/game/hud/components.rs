@@ -0,0 +1,23 @@
+use bevy::prelude::Component;
+
+#[derive(Component)]
+pub struct Hud;
+
+#[derive(Component)]
+pub struct ScoreText {
+    pub player: usize,
+}
+
+#[derive(Component)]
+pub struct LivesText;
+
+#[derive(Component)]
+pub struct DashCooldownBar {
+    pub player: usize,
+}
+
+#[derive(Component)]
+pub struct ObjectiveText;
+
+#[derive(Component)]
+pub struct TimeLimitText;
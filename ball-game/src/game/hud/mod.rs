@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+use crate::{
+    game::{
+        campaign::resources::CampaignRun, mode::resources::TimeLimit, player::resources::Lives,
+    },
+    AppState,
+};
+
+use self::systems::*;
+
+pub mod components;
+mod styles;
+mod systems;
+
+pub struct HudPlugin;
+
+impl Plugin for HudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::InGame), spawn_hud)
+            .add_systems(
+                Update,
+                (
+                    update_score_text,
+                    update_lives_text.run_if(resource_exists::<Lives>()),
+                    update_objective_text.run_if(resource_exists::<CampaignRun>()),
+                    update_time_limit_text.run_if(resource_exists::<TimeLimit>()),
+                    update_dash_cooldown_bar,
+                )
+                    .run_if(in_state(AppState::InGame)),
+            )
+            .add_systems(OnExit(AppState::InGame), despawn_hud);
+    }
+}
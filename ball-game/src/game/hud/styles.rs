@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+
+use crate::loading::resources::GameAssets;
+
+pub const DASH_BAR_BACKGROUND_COLOR: Color = Color::rgba(0.15, 0.15, 0.15, 0.8);
+pub const DASH_CHARGING_COLOR: Color = Color::rgb(0.5, 0.5, 0.5);
+pub const DASH_READY_COLOR: Color = Color::rgb(0.4, 0.7, 1.0);
+
+pub fn get_hud_style() -> Style {
+    Style {
+        position_type: PositionType::Absolute,
+        left: Val::Px(16.0),
+        top: Val::Px(16.0),
+        flex_direction: FlexDirection::Column,
+        row_gap: Val::Px(8.0),
+        ..default()
+    }
+}
+
+pub fn get_dash_bar_background_style() -> Style {
+    Style {
+        width: Val::Px(160.0),
+        height: Val::Px(12.0),
+        ..Style::DEFAULT
+    }
+}
+
+pub fn get_dash_bar_style() -> Style {
+    Style {
+        width: Val::Percent(100.0),
+        height: Val::Percent(100.0),
+        ..Style::DEFAULT
+    }
+}
+
+pub fn get_hud_text_style(game_assets: &GameAssets) -> TextStyle {
+    TextStyle {
+        font: game_assets.font.clone(),
+        font_size: 28.0,
+        color: Color::WHITE,
+    }
+}
@@ -0,0 +1,179 @@
+use bevy::prelude::*;
+
+use super::{
+    components::{DashCooldownBar, Hud, LivesText, ObjectiveText, ScoreText, TimeLimitText},
+    styles::{
+        get_dash_bar_background_style, get_dash_bar_style, get_hud_style, get_hud_text_style,
+        DASH_BAR_BACKGROUND_COLOR, DASH_CHARGING_COLOR, DASH_READY_COLOR,
+    },
+};
+use crate::{
+    game::{
+        campaign::{resources::CampaignRun, stages::STAGES},
+        mode::resources::{GameMode, TimeLimit},
+        player::{
+            components::{Dash, Player},
+            resources::{Lives, PlayerMode},
+        },
+        score::resources::Score,
+    },
+    loading::resources::GameAssets,
+    localization::{components::LocalizedText, resources::LocalizedString},
+};
+
+fn score_label(player_mode: PlayerMode, player: usize, score: u32) -> LocalizedString {
+    match player_mode {
+        PlayerMode::Single => LocalizedString::new("hud.score").with_arg("score", score),
+        PlayerMode::Coop | PlayerMode::Versus => LocalizedString::new("hud.player_score")
+            .with_arg("player", player + 1)
+            .with_arg("score", score),
+    }
+}
+
+pub fn spawn_hud(
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    player_mode: Res<PlayerMode>,
+    game_mode: Res<GameMode>,
+    campaign_run: Option<Res<CampaignRun>>,
+) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: get_hud_style(),
+                ..default()
+            },
+            Hud {},
+        ))
+        .with_children(|parent| {
+            for player in 0..player_mode.player_count() {
+                // === Score ===
+                parent.spawn((
+                    TextBundle::from_section("", get_hud_text_style(&game_assets)),
+                    LocalizedText::new(score_label(*player_mode, player, 0)),
+                    ScoreText { player },
+                ));
+                // === Dash Cooldown ===
+                parent
+                    .spawn(NodeBundle {
+                        style: get_dash_bar_background_style(),
+                        background_color: DASH_BAR_BACKGROUND_COLOR.into(),
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        parent.spawn((
+                            NodeBundle {
+                                style: get_dash_bar_style(),
+                                background_color: DASH_READY_COLOR.into(),
+                                ..default()
+                            },
+                            DashCooldownBar { player },
+                        ));
+                    });
+            }
+            // === Lives ===
+            if player_mode.starting_lives() > 0 {
+                parent.spawn((
+                    TextBundle::from_section("", get_hud_text_style(&game_assets)),
+                    LocalizedText::new(LocalizedString::raw("")),
+                    LivesText {},
+                ));
+            }
+            // === Time Limit ===
+            if *game_mode == GameMode::TimeAttack {
+                parent.spawn((
+                    TextBundle::from_section("", get_hud_text_style(&game_assets)),
+                    LocalizedText::new(LocalizedString::raw("")),
+                    TimeLimitText {},
+                ));
+            }
+            // === Campaign Objective ===
+            if let Some(campaign_run) = campaign_run {
+                parent.spawn((
+                    TextBundle::from_section("", get_hud_text_style(&game_assets)),
+                    LocalizedText::new(STAGES[campaign_run.stage].objective.description()),
+                    ObjectiveText {},
+                ));
+            }
+        });
+}
+
+pub fn despawn_hud(mut commands: Commands, hud_query: Query<Entity, With<Hud>>) {
+    if let Ok(hud_entity) = hud_query.get_single() {
+        commands.entity(hud_entity).despawn_recursive();
+    }
+}
+
+pub fn update_score_text(
+    mut text_query: Query<(&mut LocalizedText, &ScoreText)>,
+    score: Res<Score>,
+    player_mode: Res<PlayerMode>,
+) {
+    if !score.is_changed() {
+        return;
+    }
+    for (mut text, score_text) in text_query.iter_mut() {
+        let player_score = score.players[score_text.player];
+        text.sections[0] = score_label(*player_mode, score_text.player, player_score);
+    }
+}
+
+pub fn update_lives_text(
+    mut text_query: Query<&mut LocalizedText, With<LivesText>>,
+    lives: Res<Lives>,
+) {
+    if !lives.is_changed() {
+        return;
+    }
+    if let Ok(mut text) = text_query.get_single_mut() {
+        text.sections[0] = LocalizedString::new("hud.lives").with_arg("lives", lives.remaining);
+    }
+}
+
+pub fn update_objective_text(
+    mut text_query: Query<&mut LocalizedText, With<ObjectiveText>>,
+    campaign_run: Res<CampaignRun>,
+    score: Res<Score>,
+) {
+    if let Ok(mut text) = text_query.get_single_mut() {
+        let stars = score.players.iter().sum();
+        text.sections[0] =
+            STAGES[campaign_run.stage]
+                .objective
+                .progress(campaign_run.elapsed, stars, score.value);
+    }
+}
+
+pub fn update_time_limit_text(
+    mut text_query: Query<&mut LocalizedText, With<TimeLimitText>>,
+    time_limit: Res<TimeLimit>,
+) {
+    if let Ok(mut text) = text_query.get_single_mut() {
+        text.sections[0] = LocalizedString::new("hud.time")
+            .with_arg("time", time_limit.timer.remaining_secs().ceil());
+    }
+}
+
+pub fn update_dash_cooldown_bar(
+    mut bar_query: Query<(&mut Style, &mut BackgroundColor, &DashCooldownBar)>,
+    dash_query: Query<(&Player, &Dash)>,
+) {
+    for (mut style, mut background_color, bar) in bar_query.iter_mut() {
+        let dash = dash_query
+            .iter()
+            .find(|(player, _)| player.id == bar.player)
+            .map(|(_, dash)| dash);
+
+        // An empty bar while the player is down.
+        let charge = match dash {
+            Some(dash) if !dash.dashing() => dash.cooldown.percent(),
+            _ => 0.0,
+        };
+        style.width = Val::Percent(100.0 * charge);
+        *background_color = if dash.is_some_and(|dash| dash.ready()) {
+            DASH_READY_COLOR.into()
+        } else {
+            DASH_CHARGING_COLOR.into()
+        };
+    }
+}
@@ -0,0 +1,90 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+use rand::Rng;
+
+use super::{
+    asset::{Area, Level},
+    resources::{PlaytestLevel, SelectedLevel},
+};
+use crate::{
+    game::{mode::resources::GameMode, resources::GameRng},
+    loading::resources::GameAssets,
+};
+
+/// Attempts at finding a spawn point that isn't inside an obstacle before giving up.
+const SPAWN_ATTEMPTS: usize = 16;
+
+pub enum SpawnZone {
+    Stars,
+    Enemies,
+}
+
+/// The level being played, if it's not the open arena and it loaded.
+#[derive(SystemParam)]
+pub struct ActiveLevel<'w> {
+    playtest_level: Option<Res<'w, PlaytestLevel>>,
+    selected_level: Res<'w, SelectedLevel>,
+    game_mode: Res<'w, GameMode>,
+    game_assets: Res<'w, GameAssets>,
+    levels: Res<'w, Assets<Level>>,
+}
+
+impl<'w> ActiveLevel<'w> {
+    pub fn get(&self) -> Option<&Level> {
+        if let Some(playtest_level) = &self.playtest_level {
+            return Some(&playtest_level.0);
+        }
+        // The daily layout has to be the same for everyone, whatever they picked.
+        if *self.game_mode == GameMode::DailyChallenge {
+            return None;
+        }
+        self.selected()
+    }
+
+    /// The level picked in the level select, regardless of what is being played.
+    pub fn selected(&self) -> Option<&Level> {
+        let handle = self.game_assets.levels.get(self.selected_level.level?)?;
+        self.levels.get(handle)
+    }
+
+    /// Random point in one of the level's zones, or anywhere in the window without any.
+    pub fn spawn_point(&self, zone: SpawnZone, window: &Window, rng: &mut GameRng) -> Vec2 {
+        let window_area = Area {
+            center: Vec2::splat(0.5),
+            size: Vec2::ONE,
+        };
+        let (zones, obstacles) = match self.get() {
+            Some(level) => {
+                let zones = match zone {
+                    SpawnZone::Stars => &level.star_zones,
+                    SpawnZone::Enemies => &level.enemy_zones,
+                };
+                (zones.as_slice(), level.obstacles.as_slice())
+            }
+            None => (&[][..], &[][..]),
+        };
+
+        let mut point = Vec2::ZERO;
+        for _ in 0..SPAWN_ATTEMPTS {
+            let area = if zones.is_empty() {
+                &window_area
+            } else {
+                &zones[rng.gen_range(0..zones.len())]
+            };
+            let rect = area.rect(window);
+            point = rect.min + Vec2::new(rng.gen::<f32>(), rng.gen::<f32>()) * rect.size();
+
+            let blocked = obstacles
+                .iter()
+                .any(|obstacle| obstacle.rect(window).contains(point));
+            if !blocked {
+                break;
+            }
+        }
+        point
+    }
+}
+
+/// Converts a position in window fractions to world coordinates.
+pub fn to_world(position: Vec2, window: &Window) -> Vec2 {
+    position * Vec2::new(window.width(), window.height())
+}
@@ -0,0 +1,76 @@
+use bevy::{
+    asset::{AssetLoader, LoadContext, LoadedAsset},
+    prelude::*,
+    reflect::{TypePath, TypeUuid},
+    utils::BoxedFuture,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::game::enemy::components::EnemyKind;
+
+/// A rectangle in window fractions, from `(0.0, 0.0)` at the bottom left to `(1.0, 1.0)` at the
+/// top right, so layouts scale with the window.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Area {
+    pub center: Vec2,
+    pub size: Vec2,
+}
+
+impl Area {
+    pub fn rect(&self, window: &Window) -> Rect {
+        let scale = Vec2::new(window.width(), window.height());
+        Rect::from_center_size(self.center * scale, self.size * scale)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnemyPlacement {
+    pub kind: EnemyKind,
+    /// In window fractions, like `Area`.
+    pub position: Vec2,
+    /// Picked at random when left out.
+    #[serde(default)]
+    pub direction: Option<Vec2>,
+}
+
+/// Layout of a playfield, read from `assets/levels/*.level.ron`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TypeUuid, TypePath)]
+#[uuid = "6f3f2a4e-1d2b-4c8a-9b77-3e5c2d1a0f42"]
+pub struct Level {
+    pub name: String,
+    #[serde(default)]
+    pub obstacles: Vec<Area>,
+    /// Stars present when the level starts.
+    #[serde(default)]
+    pub stars: Vec<Vec2>,
+    /// Enemies present when the level starts.
+    #[serde(default)]
+    pub enemies: Vec<EnemyPlacement>,
+    /// Where stars appear over time; anywhere in the window when empty.
+    #[serde(default)]
+    pub star_zones: Vec<Area>,
+    /// Where enemies appear over time; anywhere in the window when empty.
+    #[serde(default)]
+    pub enemy_zones: Vec<Area>,
+}
+
+#[derive(Default)]
+pub struct LevelLoader;
+
+impl AssetLoader for LevelLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let level = ron::de::from_bytes::<Level>(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(level));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["level.ron"]
+    }
+}
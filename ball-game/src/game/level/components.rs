@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+/// A static wall that players stop against and enemies bounce off.
+#[derive(Component)]
+pub struct Obstacle {
+    pub half_size: Vec2,
+}
+
+impl Obstacle {
+    /// Where a circle overlapping this obstacle has to move to stop touching it, along with the
+    /// normal of the side it leaves through.
+    pub fn push_out(&self, center: Vec2, position: Vec2, radius: f32) -> Option<(Vec2, Vec2)> {
+        let offset = position - center;
+        let closest = offset.clamp(-self.half_size, self.half_size);
+        let outside = offset - closest;
+        let distance = outside.length();
+        if distance >= radius {
+            return None;
+        }
+
+        if distance > 0.0 {
+            let normal = outside / distance;
+            return Some((center + closest + normal * radius, normal));
+        }
+
+        // The center itself is inside, so leave through the nearest side.
+        let depth = self.half_size - offset.abs();
+        if depth.x < depth.y {
+            let normal = Vec2::new(offset.x.signum(), 0.0);
+            let x = center.x + normal.x * (self.half_size.x + radius);
+            Some((Vec2::new(x, position.y), normal))
+        } else {
+            let normal = Vec2::new(0.0, offset.y.signum());
+            let y = center.y + normal.y * (self.half_size.y + radius);
+            Some((Vec2::new(position.x, y), normal))
+        }
+    }
+}
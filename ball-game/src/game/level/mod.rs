@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+use crate::AppState;
+
+use self::{resources::SelectedLevel, systems::*};
+
+use super::{player::PlayerSystemSet, SimulationState};
+
+pub mod active;
+pub mod asset;
+pub mod components;
+pub mod resources;
+mod systems;
+
+pub const OBSTACLE_COLOR: Color = Color::rgb(0.3, 0.3, 0.4);
+
+pub struct LevelPlugin;
+
+impl Plugin for LevelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SelectedLevel>()
+            .add_systems(OnEnter(AppState::InGame), spawn_obstacles)
+            .add_systems(
+                Update,
+                confine_players_to_obstacles
+                    .in_set(PlayerSystemSet::Confinement)
+                    .run_if(in_state(AppState::InGame))
+                    .run_if(in_state(SimulationState::Running)),
+            )
+            .add_systems(
+                Update,
+                bounce_enemies_off_obstacles
+                    .run_if(in_state(AppState::InGame))
+                    .run_if(in_state(SimulationState::Running)),
+            )
+            .add_systems(OnExit(AppState::InGame), despawn_obstacles);
+    }
+}
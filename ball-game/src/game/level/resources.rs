@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+use super::asset::Level;
+
+/// Index into `GameAssets::levels`; `None` plays the open arena.
+#[derive(Resource, Default)]
+pub struct SelectedLevel {
+    pub level: Option<usize>,
+}
+
+/// Layout from the editor being test played; takes precedence over `SelectedLevel`.
+#[derive(Resource)]
+pub struct PlaytestLevel(pub Level);
@@ -0,0 +1,122 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use super::{active::ActiveLevel, components::Obstacle, OBSTACLE_COLOR};
+use crate::{
+    events::{EnemyBounced, ParticleEvent, SoundEvent},
+    game::{
+        enemy::components::{Enemy, Splitter},
+        particle::components::ParticleEffect,
+        player::{
+            components::{Dash, Player, Velocity},
+            PLAYER_SIZE,
+        },
+    },
+    settings::resources::ControlSettings,
+};
+
+pub fn spawn_obstacles(
+    mut commands: Commands,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    active_level: ActiveLevel,
+) {
+    let Some(level) = active_level.get() else {
+        return;
+    };
+    let window = window_query.get_single().unwrap();
+
+    for area in level.obstacles.iter() {
+        let rect = area.rect(window);
+        let center = rect.center();
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: OBSTACLE_COLOR,
+                    custom_size: Some(rect.size()),
+                    ..default()
+                },
+                transform: Transform::from_xyz(center.x, center.y, 0.0),
+                ..default()
+            },
+            Obstacle {
+                half_size: rect.half_size(),
+            },
+        ));
+    }
+}
+
+pub fn despawn_obstacles(mut commands: Commands, obstacle_query: Query<Entity, With<Obstacle>>) {
+    for obstacle_entity in obstacle_query.iter() {
+        commands.entity(obstacle_entity).despawn();
+    }
+}
+
+/// Obstacles stop players the same way the arena edges do.
+pub fn confine_players_to_obstacles(
+    mut player_query: Query<(&mut Transform, &mut Dash, &mut Velocity), With<Player>>,
+    obstacle_query: Query<(&Transform, &Obstacle), Without<Player>>,
+    control_settings: Res<ControlSettings>,
+) {
+    for (mut player_transform, mut dash, mut velocity) in player_query.iter_mut() {
+        for (obstacle_transform, obstacle) in obstacle_query.iter() {
+            let Some((position, normal)) = obstacle.push_out(
+                obstacle_transform.translation.truncate(),
+                player_transform.translation.truncate(),
+                PLAYER_SIZE / 2.0,
+            ) else {
+                continue;
+            };
+            player_transform.translation = position.extend(player_transform.translation.z);
+
+            if dash.dashing() {
+                let dash_duration = dash.duration.duration();
+                dash.duration.set_elapsed(dash_duration);
+            }
+
+            let into_obstacle = velocity.0.dot(normal);
+            if into_obstacle < 0.0 {
+                let restitution = control_settings.momentum.wall_restitution;
+                velocity.0 -= normal * into_obstacle * (1.0 + restitution);
+            }
+        }
+    }
+}
+
+/// Obstacles reflect enemies the same way the arena edges do.
+pub fn bounce_enemies_off_obstacles(
+    mut enemy_query: Query<(&mut Transform, &mut Enemy, Option<&mut Splitter>)>,
+    obstacle_query: Query<(&Transform, &Obstacle), Without<Enemy>>,
+    mut enemy_bounced_event_writer: EventWriter<EnemyBounced>,
+    mut sound_event_writer: EventWriter<SoundEvent>,
+    mut particle_event_writer: EventWriter<ParticleEvent>,
+) {
+    for (mut transform, mut enemy, mut splitter) in enemy_query.iter_mut() {
+        for (obstacle_transform, obstacle) in obstacle_query.iter() {
+            let Some((position, normal)) = obstacle.push_out(
+                obstacle_transform.translation.truncate(),
+                transform.translation.truncate(),
+                enemy.size / 2.0,
+            ) else {
+                continue;
+            };
+            transform.translation = position.extend(transform.translation.z);
+
+            let into_obstacle = enemy.direction.dot(normal);
+            if into_obstacle >= 0.0 {
+                continue;
+            }
+            enemy.direction -= normal * into_obstacle * 2.0;
+
+            if let Some(splitter) = &mut splitter {
+                splitter.bounces_left = splitter.bounces_left.saturating_sub(1);
+            }
+
+            enemy_bounced_event_writer.send(EnemyBounced);
+            sound_event_writer.send(SoundEvent::EnemyBounce);
+            particle_event_writer.send(ParticleEvent {
+                effect: ParticleEffect::WallSpark,
+                position: position - normal * enemy.size / 2.0,
+                direction: normal,
+            });
+        }
+    }
+}
@@ -1,13 +1,31 @@
-mod enemy;
-mod player;
-mod score;
-mod star;
+pub mod boss;
+pub mod campaign;
+pub mod enemy;
+mod hud;
+pub mod level;
+pub mod mode;
+pub mod particle;
+pub mod player;
+pub mod resources;
+pub mod score;
+pub mod star;
 mod systems;
 
-use crate::{events::GameOver, AppState};
+use crate::{
+    events::{EnemyBounced, GameOver, PlayerDied, StarCollected},
+    save::resources::ResumeRun,
+    AppState,
+};
 use bevy::prelude::*;
+use boss::BossPlugin;
+use campaign::CampaignPlugin;
 use enemy::EnemyPlugin;
+use hud::HudPlugin;
+use level::LevelPlugin;
+use mode::GameModePlugin;
+use particle::ParticlePlugin;
 use player::PlayerPlugin;
+use resources::GameRng;
 use score::ScorePlugin;
 use star::StarPlugin;
 use systems::*;
@@ -18,8 +36,22 @@ impl Plugin for GamePlugin {
     fn build(&self, app: &mut App) {
         app.add_state::<SimulationState>()
             .add_event::<GameOver>()
+            .add_event::<PlayerDied>()
+            .add_event::<StarCollected>()
+            .add_event::<EnemyBounced>()
+            .init_resource::<GameRng>()
             .add_systems(OnEnter(AppState::InGame), pause_simulation)
+            .add_systems(
+                OnEnter(AppState::InGame),
+                seed_game_rng.run_if(not(resource_exists::<ResumeRun>())),
+            )
+            .add_plugins(BossPlugin)
+            .add_plugins(CampaignPlugin)
             .add_plugins(EnemyPlugin)
+            .add_plugins(HudPlugin)
+            .add_plugins(LevelPlugin)
+            .add_plugins(GameModePlugin)
+            .add_plugins(ParticlePlugin)
             .add_plugins(PlayerPlugin)
             .add_plugins(ScorePlugin)
             .add_plugins(StarPlugin)
@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+use crate::{save::resources::ResumeRun, AppState};
+
+use self::{
+    resources::{GameMode, TimeLimit},
+    systems::*,
+};
+
+use super::SimulationState;
+
+pub mod resources;
+mod systems;
+
+const TIME_ATTACK_SECONDS: f32 = 60.0;
+
+pub struct GameModePlugin;
+
+impl Plugin for GameModePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameMode>()
+            .add_systems(
+                OnEnter(AppState::InGame),
+                insert_time_limit
+                    .run_if(resource_equals(GameMode::TimeAttack))
+                    .run_if(not(resource_exists::<ResumeRun>())),
+            )
+            .add_systems(
+                Update,
+                tick_time_limit
+                    .run_if(in_state(AppState::InGame))
+                    .run_if(in_state(SimulationState::Running))
+                    .run_if(resource_exists::<TimeLimit>()),
+            )
+            .add_systems(OnExit(AppState::InGame), remove_time_limit);
+    }
+}
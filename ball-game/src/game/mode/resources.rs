@@ -0,0 +1,65 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::TIME_ATTACK_SECONDS;
+
+/// What a run is played for, picked from the main menu alongside the player mode.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum GameMode {
+    /// Play until nobody is left standing.
+    #[default]
+    Endless,
+    /// Score as much as possible before the clock runs out.
+    TimeAttack,
+    /// The same open arena layout for everyone, changing every day.
+    DailyChallenge,
+}
+
+impl GameMode {
+    pub const ALL: [GameMode; 3] = [
+        GameMode::Endless,
+        GameMode::TimeAttack,
+        GameMode::DailyChallenge,
+    ];
+
+    /// String table key.
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            GameMode::Endless => "game_mode.endless",
+            GameMode::TimeAttack => "game_mode.time_attack",
+            GameMode::DailyChallenge => "game_mode.daily",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            GameMode::Endless => GameMode::TimeAttack,
+            GameMode::TimeAttack => GameMode::DailyChallenge,
+            GameMode::DailyChallenge => GameMode::Endless,
+        }
+    }
+}
+
+/// Days since the Unix epoch in UTC, so everyone shares the same seed on a given date.
+pub fn daily_seed() -> u64 {
+    let since_epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    since_epoch.as_secs() / (24 * 60 * 60)
+}
+
+/// Time left in a time attack run.
+#[derive(Resource)]
+pub struct TimeLimit {
+    pub timer: Timer,
+}
+
+impl Default for TimeLimit {
+    fn default() -> Self {
+        TimeLimit {
+            timer: Timer::from_seconds(TIME_ATTACK_SECONDS, TimerMode::Once),
+        }
+    }
+}
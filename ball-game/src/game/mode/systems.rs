@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+use super::resources::TimeLimit;
+use crate::{
+    events::GameOver,
+    game::score::resources::Score,
+    localization::{console::PrintLocalized, resources::LocalizedString},
+};
+
+pub fn insert_time_limit(mut commands: Commands) {
+    commands.insert_resource(TimeLimit::default());
+}
+
+pub fn remove_time_limit(mut commands: Commands) {
+    commands.remove_resource::<TimeLimit>();
+}
+
+pub fn tick_time_limit(
+    mut commands: Commands,
+    mut time_limit: ResMut<TimeLimit>,
+    mut game_over_event_writer: EventWriter<GameOver>,
+    score: Res<Score>,
+    time: Res<Time>,
+) {
+    time_limit.timer.tick(time.delta());
+    if time_limit.timer.just_finished() {
+        commands.add(PrintLocalized(LocalizedString::new("console.times_up")));
+        game_over_event_writer.send(GameOver {
+            score: score.value,
+            winner: None,
+        });
+    }
+}
@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParticleEffect {
+    StarPickup,
+    PlayerDeath,
+    WallSpark,
+    DashTrail,
+}
+
+/// Data describing how an emitter spawns particles and how they evolve over their lifetime.
+pub struct EmitterConfig {
+    pub particles_per_burst: u32,
+    pub bursts: u32,
+    pub burst_interval: f32,
+    /// Half-angle (in radians) of the cone around the emission direction.
+    pub spread: f32,
+    pub min_speed: f32,
+    pub max_speed: f32,
+    pub drag: f32,
+    pub lifetime: f32,
+    pub start_color: Color,
+    pub end_color: Color,
+    pub start_size: f32,
+    pub end_size: f32,
+}
+
+impl ParticleEffect {
+    pub fn config(&self) -> &'static EmitterConfig {
+        match self {
+            ParticleEffect::StarPickup => &STAR_PICKUP,
+            ParticleEffect::PlayerDeath => &PLAYER_DEATH,
+            ParticleEffect::WallSpark => &WALL_SPARK,
+            ParticleEffect::DashTrail => &DASH_TRAIL,
+        }
+    }
+}
+
+const STAR_PICKUP: EmitterConfig = EmitterConfig {
+    particles_per_burst: 16,
+    bursts: 1,
+    burst_interval: 0.0,
+    spread: std::f32::consts::PI,
+    min_speed: 60.0,
+    max_speed: 180.0,
+    drag: 3.0,
+    lifetime: 0.5,
+    start_color: Color::rgba(1.0, 0.9, 0.3, 1.0),
+    end_color: Color::rgba(1.0, 0.6, 0.1, 0.0),
+    start_size: 8.0,
+    end_size: 2.0,
+};
+
+const PLAYER_DEATH: EmitterConfig = EmitterConfig {
+    particles_per_burst: 24,
+    bursts: 3,
+    burst_interval: 0.08,
+    spread: std::f32::consts::PI,
+    min_speed: 100.0,
+    max_speed: 350.0,
+    drag: 2.0,
+    lifetime: 0.9,
+    start_color: Color::rgba(0.4, 0.7, 1.0, 1.0),
+    end_color: Color::rgba(0.9, 0.2, 0.2, 0.0),
+    start_size: 12.0,
+    end_size: 3.0,
+};
+
+const WALL_SPARK: EmitterConfig = EmitterConfig {
+    particles_per_burst: 5,
+    bursts: 1,
+    burst_interval: 0.0,
+    spread: 0.6,
+    min_speed: 80.0,
+    max_speed: 200.0,
+    drag: 6.0,
+    lifetime: 0.25,
+    start_color: Color::rgba(1.0, 1.0, 0.8, 1.0),
+    end_color: Color::rgba(1.0, 0.4, 0.2, 0.0),
+    start_size: 4.0,
+    end_size: 1.0,
+};
+
+const DASH_TRAIL: EmitterConfig = EmitterConfig {
+    particles_per_burst: 2,
+    bursts: 1,
+    burst_interval: 0.0,
+    spread: 0.4,
+    min_speed: 20.0,
+    max_speed: 60.0,
+    drag: 4.0,
+    lifetime: 0.3,
+    start_color: Color::rgba(0.4, 0.7, 1.0, 0.8),
+    end_color: Color::rgba(0.4, 0.7, 1.0, 0.0),
+    start_size: 14.0,
+    end_size: 4.0,
+};
+
+#[derive(Component)]
+pub struct ParticleEmitter {
+    pub effect: ParticleEffect,
+    /// Emission direction, particles spread around it.
+    pub direction: Vec2,
+    pub bursts_left: u32,
+    pub timer: Timer,
+}
+
+#[derive(Component)]
+pub struct Particle {
+    pub effect: ParticleEffect,
+    pub velocity: Vec2,
+    pub lifetime: Timer,
+}
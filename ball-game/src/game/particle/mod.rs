@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+use crate::{events::ParticleEvent, AppState};
+
+use self::{resources::ParticlePool, systems::*};
+
+use super::SimulationState;
+
+pub mod components;
+pub mod resources;
+mod systems;
+
+/// Upper bound on live particle entities, pooled or not.
+const MAX_PARTICLES: usize = 512;
+const PARTICLE_Z: f32 = 0.5;
+
+pub struct ParticlePlugin;
+
+impl Plugin for ParticlePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ParticleEvent>()
+            .init_resource::<ParticlePool>()
+            .add_systems(
+                Update,
+                (spawn_particle_emitters, emit_particles, update_particles)
+                    .chain()
+                    // Keep the death burst animating on the game over screen.
+                    .run_if(in_state(AppState::InGame).or_else(in_state(AppState::GameOver)))
+                    .run_if(in_state(SimulationState::Running)),
+            )
+            .add_systems(OnEnter(AppState::InGame), despawn_particles)
+            .add_systems(OnEnter(AppState::MainMenu), despawn_particles);
+    }
+}
@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+/// Hidden particle entities ready to be reused instead of spawning new ones.
+#[derive(Resource, Default)]
+pub struct ParticlePool {
+    pub free: Vec<Entity>,
+    pub total: usize,
+}
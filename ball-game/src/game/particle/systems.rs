@@ -0,0 +1,183 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use rand::random;
+
+use super::{
+    components::{EmitterConfig, Particle, ParticleEmitter},
+    resources::ParticlePool,
+    MAX_PARTICLES, PARTICLE_Z,
+};
+use crate::events::ParticleEvent;
+
+type ParticleEntity = Or<(With<Particle>, With<ParticleEmitter>)>;
+
+pub fn spawn_particle_emitters(
+    mut commands: Commands,
+    mut particle_event_reader: EventReader<ParticleEvent>,
+) {
+    for event in particle_event_reader.iter() {
+        let config = event.effect.config();
+        let direction = if event.direction.length_squared() > 0.0 {
+            event.direction.normalize()
+        } else {
+            Vec2::X
+        };
+
+        commands.spawn((
+            TransformBundle::from_transform(Transform::from_xyz(
+                event.position.x,
+                event.position.y,
+                PARTICLE_Z,
+            )),
+            ParticleEmitter {
+                effect: event.effect,
+                direction,
+                bursts_left: config.bursts,
+                // The first burst fires on the next tick.
+                timer: Timer::from_seconds(0.0, TimerMode::Once),
+            },
+        ));
+    }
+}
+
+fn lerp_color(start: Color, end: Color, t: f32) -> Color {
+    let [r0, g0, b0, a0] = start.as_rgba_f32();
+    let [r1, g1, b1, a1] = end.as_rgba_f32();
+    Color::rgba(
+        r0 + (r1 - r0) * t,
+        g0 + (g1 - g0) * t,
+        b0 + (b1 - b0) * t,
+        a0 + (a1 - a0) * t,
+    )
+}
+
+fn random_velocity(config: &EmitterConfig, direction: Vec2) -> Vec2 {
+    let base_angle = direction.y.atan2(direction.x);
+    let angle = if config.spread >= std::f32::consts::PI {
+        random::<f32>() * TAU
+    } else {
+        base_angle + (random::<f32>() * 2.0 - 1.0) * config.spread
+    };
+    let speed = config.min_speed + random::<f32>() * (config.max_speed - config.min_speed);
+    Vec2::new(angle.cos(), angle.sin()) * speed
+}
+
+pub fn emit_particles(
+    mut commands: Commands,
+    mut emitter_query: Query<(Entity, &GlobalTransform, &mut ParticleEmitter)>,
+    mut particle_query: Query<(&mut Particle, &mut Transform, &mut Sprite, &mut Visibility)>,
+    mut particle_pool: ResMut<ParticlePool>,
+    time: Res<Time>,
+) {
+    for (emitter_entity, emitter_transform, mut emitter) in emitter_query.iter_mut() {
+        // Wait a frame so the emitter's global transform has been propagated.
+        if emitter.is_added() {
+            continue;
+        }
+
+        emitter.timer.tick(time.delta());
+        if !emitter.timer.finished() {
+            continue;
+        }
+
+        let config = emitter.effect.config();
+        let position = emitter_transform.translation();
+
+        for _ in 0..config.particles_per_burst {
+            let particle = Particle {
+                effect: emitter.effect,
+                velocity: random_velocity(config, emitter.direction),
+                lifetime: Timer::from_seconds(config.lifetime, TimerMode::Once),
+            };
+            let transform = Transform::from_xyz(position.x, position.y, PARTICLE_Z);
+            let sprite = Sprite {
+                color: config.start_color,
+                custom_size: Some(Vec2::splat(config.start_size)),
+                ..default()
+            };
+
+            if let Some(pooled_entity) = particle_pool.free.pop() {
+                if let Ok((
+                    mut pooled_particle,
+                    mut pooled_transform,
+                    mut pooled_sprite,
+                    mut visibility,
+                )) = particle_query.get_mut(pooled_entity)
+                {
+                    *pooled_particle = particle;
+                    *pooled_transform = transform;
+                    *pooled_sprite = sprite;
+                    *visibility = Visibility::Visible;
+                }
+            } else if particle_pool.total < MAX_PARTICLES {
+                particle_pool.total += 1;
+                commands.spawn((
+                    SpriteBundle {
+                        sprite,
+                        transform,
+                        ..default()
+                    },
+                    particle,
+                ));
+            }
+        }
+
+        emitter.bursts_left = emitter.bursts_left.saturating_sub(1);
+        if emitter.bursts_left == 0 {
+            commands.entity(emitter_entity).despawn();
+        } else {
+            emitter.timer = Timer::from_seconds(config.burst_interval, TimerMode::Once);
+        }
+    }
+}
+
+pub fn update_particles(
+    mut particle_query: Query<(
+        Entity,
+        &mut Particle,
+        &mut Transform,
+        &mut Sprite,
+        &mut Visibility,
+    )>,
+    mut particle_pool: ResMut<ParticlePool>,
+    time: Res<Time>,
+) {
+    for (particle_entity, mut particle, mut transform, mut sprite, mut visibility) in
+        particle_query.iter_mut()
+    {
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+
+        particle.lifetime.tick(time.delta());
+        if particle.lifetime.finished() {
+            *visibility = Visibility::Hidden;
+            particle_pool.free.push(particle_entity);
+            continue;
+        }
+
+        let config = particle.effect.config();
+        let t = particle.lifetime.percent();
+        let delta = time.delta_seconds();
+
+        let velocity = particle.velocity * (1.0 - config.drag * delta).max(0.0);
+        particle.velocity = velocity;
+        transform.translation += velocity.extend(0.0) * delta;
+
+        sprite.color = lerp_color(config.start_color, config.end_color, t);
+        let size = config.start_size + (config.end_size - config.start_size) * t;
+        sprite.custom_size = Some(Vec2::splat(size));
+    }
+}
+
+pub fn despawn_particles(
+    mut commands: Commands,
+    particle_query: Query<Entity, ParticleEntity>,
+    mut particle_pool: ResMut<ParticlePool>,
+) {
+    for entity in particle_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    *particle_pool = ParticlePool::default();
+}
@@ -1,4 +1,55 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
+#[derive(Component, Clone, Serialize, Deserialize)]
+pub struct Player {
+    /// Index into the per-player bindings, colors and scores.
+    pub id: usize,
+}
+
+#[derive(Component, Clone, Serialize, Deserialize)]
+pub struct Dash {
+    pub cooldown: Timer,
+    pub duration: Timer,
+    /// Direction of the last movement input, which the dash follows.
+    pub direction: Vec2,
+}
+
+impl Dash {
+    pub fn dashing(&self) -> bool {
+        !self.duration.finished()
+    }
+
+    pub fn ready(&self) -> bool {
+        self.cooldown.finished() && !self.dashing()
+    }
+
+    pub fn start(&mut self) {
+        self.duration.reset();
+        self.cooldown.reset();
+    }
+}
+
+/// Enemies and bullets pass through the player while this is present.
+#[derive(Component, Clone, Serialize, Deserialize)]
+pub struct Invulnerable {
+    pub timer: Timer,
+}
+
+impl Invulnerable {
+    pub fn new(duration: f32) -> Self {
+        Invulnerable {
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+        }
+    }
+}
+
+#[derive(Component, Default, Clone, Serialize, Deserialize)]
+pub struct Velocity(pub Vec2);
+
+#[derive(Component, Default, Clone, Serialize, Deserialize)]
+pub struct Acceleration(pub Vec2);
+
+/// Marks where the player is heading with the pointer control scheme.
 #[derive(Component)]
-pub struct Player {}
+pub struct TargetIndicator;
@@ -0,0 +1,30 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use crate::{
+    events::{CameraEvent, DeathCause, ParticleEvent, PlayerDied, SoundEvent},
+    game::particle::components::ParticleEffect,
+};
+
+/// Everything that has to be announced when a player dies, whatever killed them.
+#[derive(SystemParam)]
+pub struct PlayerDeathEvents<'w> {
+    player_died_event_writer: EventWriter<'w, PlayerDied>,
+    sound_event_writer: EventWriter<'w, SoundEvent>,
+    particle_event_writer: EventWriter<'w, ParticleEvent>,
+    camera_event_writer: EventWriter<'w, CameraEvent>,
+}
+
+impl PlayerDeathEvents<'_> {
+    pub fn send(&mut self, player: usize, position: Vec2, cause: DeathCause) {
+        self.sound_event_writer.send(SoundEvent::PlayerDeath);
+        self.particle_event_writer.send(ParticleEvent {
+            effect: ParticleEffect::PlayerDeath,
+            position,
+            direction: Vec2::ZERO,
+        });
+        self.camera_event_writer.send(CameraEvent::Shake(0.8));
+        self.camera_event_writer.send(CameraEvent::ZoomPunch(0.15));
+        self.player_died_event_writer
+            .send(PlayerDied { player, cause });
+    }
+}
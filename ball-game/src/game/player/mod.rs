@@ -1,13 +1,24 @@
-use crate::AppState;
+use crate::{save::resources::ResumeRun, AppState};
 
-use self::systems::*;
+use self::{resources::*, systems::*};
 use bevy::prelude::*;
 
 use super::SimulationState;
 
 pub mod components;
+pub mod death;
+pub mod pickup;
+pub mod resources;
 mod systems;
 
+pub use systems::spawn_player;
+
+pub const PLAYER_SIZE: f32 = 64.0;
+pub const PLAYER_SPEED: f32 = 500.0;
+pub const MAX_PLAYERS: usize = 2;
+/// Sprite tint per player, so both balls can share one texture.
+pub const PLAYER_COLORS: [Color; MAX_PLAYERS] = [Color::WHITE, Color::rgb(0.4, 1.0, 0.5)];
+
 // #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
 // pub struct MovementSystemSet;
 //
@@ -28,11 +39,28 @@ impl Plugin for PlayerPlugin {
             Update,
             PlayerSystemSet::Movement.before(PlayerSystemSet::Confinement),
         )
-        .add_systems(OnEnter(AppState::InGame), spawn_player)
+        .init_resource::<PointerTarget>()
+        .init_resource::<PlayerMode>()
+        .add_systems(
+            OnEnter(AppState::InGame),
+            (
+                (insert_lives, spawn_players).run_if(not(resource_exists::<ResumeRun>())),
+                spawn_target_indicator,
+            ),
+        )
         .add_systems(
             Update,
             (
-                player_movement.in_set(PlayerSystemSet::Movement),
+                (
+                    update_pointer_target,
+                    player_movement,
+                    player_dash,
+                    dash_movement,
+                    tick_invulnerability,
+                    update_target_indicator,
+                )
+                    .chain()
+                    .in_set(PlayerSystemSet::Movement),
                 confine_player_movement.in_set(PlayerSystemSet::Confinement),
             )
                 .run_if(in_state(AppState::InGame))
@@ -40,10 +68,19 @@ impl Plugin for PlayerPlugin {
         )
         .add_systems(
             Update,
-            (player_hit_stars, player_hit_enemies)
+            (
+                player_hit_stars,
+                player_hit_enemies,
+                player_near_miss_enemies,
+                handle_player_deaths.after(player_hit_enemies),
+                tick_respawns,
+            )
                 .run_if(in_state(AppState::InGame))
                 .run_if(in_state(SimulationState::Running)),
         )
-        .add_systems(OnExit(AppState::InGame), despawn_player);
+        .add_systems(
+            OnExit(AppState::InGame),
+            (despawn_players, despawn_target_indicator, remove_lives),
+        );
     }
 }
@@ -0,0 +1,29 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use crate::{
+    events::{CameraEvent, ParticleEvent, SoundEvent, StarCollected},
+    game::particle::components::ParticleEffect,
+};
+
+/// Everything that has to be announced when a player picks up a star.
+#[derive(SystemParam)]
+pub struct StarPickupEvents<'w> {
+    star_collected_event_writer: EventWriter<'w, StarCollected>,
+    sound_event_writer: EventWriter<'w, SoundEvent>,
+    particle_event_writer: EventWriter<'w, ParticleEvent>,
+    camera_event_writer: EventWriter<'w, CameraEvent>,
+}
+
+impl StarPickupEvents<'_> {
+    pub fn send(&mut self, position: Vec2) {
+        self.sound_event_writer.send(SoundEvent::StarCollected);
+        self.particle_event_writer.send(ParticleEvent {
+            effect: ParticleEffect::StarPickup,
+            position,
+            direction: Vec2::ZERO,
+        });
+        self.camera_event_writer.send(CameraEvent::HitStop(0.05));
+        self.camera_event_writer.send(CameraEvent::ZoomPunch(0.03));
+        self.star_collected_event_writer.send(StarCollected);
+    }
+}
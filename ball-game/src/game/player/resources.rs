@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// World position the player steers towards with the pointer control scheme.
+#[derive(Resource, Default)]
+pub struct PointerTarget {
+    pub position: Option<Vec2>,
+}
+
+/// How many local players there are and how the run ends.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PlayerMode {
+    #[default]
+    Single,
+    /// Two players sharing a pool of lives; the run ends when both are down.
+    Coop,
+    /// Two players; the last one standing wins.
+    Versus,
+}
+
+impl PlayerMode {
+    pub fn player_count(&self) -> usize {
+        match self {
+            PlayerMode::Single => 1,
+            PlayerMode::Coop | PlayerMode::Versus => 2,
+        }
+    }
+
+    pub fn starting_lives(&self) -> u32 {
+        match self {
+            PlayerMode::Coop => 3,
+            PlayerMode::Single | PlayerMode::Versus => 0,
+        }
+    }
+}
+
+/// Shared respawns left in co-op.
+#[derive(Resource, Default, Clone, Serialize, Deserialize)]
+pub struct Lives {
+    pub remaining: u32,
+}
+
+/// Players waiting to come back after losing a life.
+#[derive(Resource, Default, Clone, Serialize, Deserialize)]
+pub struct Respawns {
+    pub pending: Vec<(usize, Timer)>,
+}
@@ -1,79 +1,333 @@
 use crate::{
-    events::GameOver,
+    camera::components::GameCamera,
+    events::{CameraEvent, DeathCause, GameOver, ParticleEvent, PlayerDied},
     game::{
-        enemy::{components::Enemy, ENEMY_SIZE},
+        enemy::components::Enemy,
+        particle::components::ParticleEffect,
         score::resources::Score,
         star::{components::Star, STAR_SIZE},
     },
+    loading::resources::GameAssets,
+    network::components::RemoteInput,
+    settings::resources::{ControlScheme, ControlSettings, KeyBindings},
     systems::get_bounds,
 };
 
-use super::components::Player;
-use bevy::{prelude::*, window::PrimaryWindow};
+use super::{
+    components::{Acceleration, Dash, Invulnerable, Player, TargetIndicator, Velocity},
+    death::PlayerDeathEvents,
+    pickup::StarPickupEvents,
+    resources::{Lives, PlayerMode, PointerTarget, Respawns},
+    PLAYER_COLORS, PLAYER_SIZE, PLAYER_SPEED,
+};
+use bevy::{prelude::*, utils::HashSet, window::PrimaryWindow};
+use std::time::Duration;
 
-const PLAYER_SPEED: f32 = 500.0;
-const PLAYER_SIZE: f32 = 64.0;
+const DASH_SPEED: f32 = 1600.0;
+const DASH_DURATION: f32 = 0.15;
+const DASH_COOLDOWN: f32 = 1.5;
+/// Player transparency while invulnerable.
+const DASH_ALPHA: f32 = 0.5;
+const TARGET_INDICATOR_SIZE: f32 = 16.0;
+const TARGET_INDICATOR_COLOR: Color = Color::rgba(0.4, 0.7, 1.0, 0.6);
+/// Extra gap (beyond touching) within which a passing enemy counts as a close miss.
+const NEAR_MISS_MARGIN: f32 = 24.0;
+/// Horizontal gap between players when a two-player run starts.
+const PLAYER_SPACING: f32 = 200.0;
+const RESPAWN_DELAY: f32 = 2.0;
+/// Time a respawned player can't be hit, so they don't land on an enemy and die again.
+const RESPAWN_GRACE: f32 = 2.0;
 
 pub fn spawn_player(
+    commands: &mut Commands,
+    game_assets: &GameAssets,
+    id: usize,
+    position: Vec2,
+) -> Entity {
+    // Start off ready to dash.
+    let mut cooldown = Timer::from_seconds(DASH_COOLDOWN, TimerMode::Once);
+    cooldown.tick(Duration::from_secs_f32(DASH_COOLDOWN));
+    let mut duration = Timer::from_seconds(DASH_DURATION, TimerMode::Once);
+    duration.tick(Duration::from_secs_f32(DASH_DURATION));
+
+    commands
+        .spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: PLAYER_COLORS[id],
+                    ..default()
+                },
+                transform: Transform::from_translation(position.extend(0.0)),
+                texture: game_assets.player_texture.clone(),
+                ..default()
+            },
+            Player { id },
+            Dash {
+                cooldown,
+                duration,
+                direction: Vec2::Y,
+            },
+            Velocity::default(),
+            Acceleration::default(),
+        ))
+        .id()
+}
+
+/// Where player `id` starts, spread out around the center of the window.
+fn start_position(window: &Window, id: usize, player_count: usize) -> Vec2 {
+    let center = Vec2::new(window.width() / 2.0, window.height() / 2.0);
+    let offset = id as f32 - (player_count - 1) as f32 / 2.0;
+    center + Vec2::new(offset * PLAYER_SPACING, 0.0)
+}
+
+pub fn spawn_players(
     mut commands: Commands,
     window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
+    game_assets: Res<GameAssets>,
+    player_mode: Res<PlayerMode>,
 ) {
     let window = window_query.get_single().unwrap();
+    let player_count = player_mode.player_count();
+
+    for id in 0..player_count {
+        let position = start_position(window, id, player_count);
+        spawn_player(&mut commands, &game_assets, id, position);
+    }
+}
+
+pub fn despawn_players(mut commands: Commands, player_query: Query<Entity, With<Player>>) {
+    for player_entity in player_query.iter() {
+        commands.entity(player_entity).despawn();
+    }
+}
 
+pub fn insert_lives(mut commands: Commands, player_mode: Res<PlayerMode>) {
+    commands.insert_resource(Lives {
+        remaining: player_mode.starting_lives(),
+    });
+    commands.insert_resource(Respawns::default());
+}
+
+pub fn remove_lives(mut commands: Commands) {
+    commands.remove_resource::<Lives>();
+    commands.remove_resource::<Respawns>();
+}
+
+pub fn spawn_target_indicator(mut commands: Commands) {
     commands.spawn((
         SpriteBundle {
-            transform: Transform::from_xyz(window.width() / 2.0, window.height() / 2.0, 0.0),
-            texture: asset_server.load("sprites/ball_blue_large.png"),
+            sprite: Sprite {
+                color: TARGET_INDICATOR_COLOR,
+                custom_size: Some(Vec2::splat(TARGET_INDICATOR_SIZE)),
+                ..default()
+            },
+            transform: Transform::from_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+            visibility: Visibility::Hidden,
             ..default()
         },
-        Player {},
+        TargetIndicator {},
     ));
 }
 
-pub fn despawn_player(mut commands: Commands, player_query: Query<Entity, With<Player>>) {
-    if let Ok(player_entity) = player_query.get_single() {
-        commands.entity(player_entity).despawn();
+pub fn despawn_target_indicator(
+    mut commands: Commands,
+    indicator_query: Query<Entity, With<TargetIndicator>>,
+) {
+    if let Ok(indicator_entity) = indicator_query.get_single() {
+        commands.entity(indicator_entity).despawn();
+    }
+}
+
+/// Tracks the first touch point, or else the mouse cursor, in world coordinates.
+pub fn update_pointer_target(
+    mut pointer_target: ResMut<PointerTarget>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<GameCamera>>,
+    touches: Res<Touches>,
+    control_settings: Res<ControlSettings>,
+) {
+    if control_settings.scheme != ControlScheme::Pointer {
+        pointer_target.position = None;
+        return;
+    }
+
+    let (Ok(window), Ok((camera, camera_transform))) =
+        (window_query.get_single(), camera_query.get_single())
+    else {
+        return;
+    };
+
+    let screen_position = touches
+        .iter()
+        .next()
+        .map(|touch| touch.position())
+        .or_else(|| window.cursor_position());
+
+    // Keep the last target when the pointer leaves the window.
+    if let Some(screen_position) = screen_position {
+        pointer_target.position = camera.viewport_to_world_2d(camera_transform, screen_position);
+    }
+}
+
+pub fn update_target_indicator(
+    mut indicator_query: Query<(&mut Transform, &mut Visibility), With<TargetIndicator>>,
+    pointer_target: Res<PointerTarget>,
+) {
+    if let Ok((mut transform, mut visibility)) = indicator_query.get_single_mut() {
+        match pointer_target.position {
+            Some(position) => {
+                transform.translation = position.extend(0.0);
+                *visibility = Visibility::Visible;
+            }
+            None => {
+                *visibility = Visibility::Hidden;
+            }
+        }
     }
 }
 
 pub fn player_movement(
     keyboard_input: Res<Input<KeyCode>>,
-    mut player_query: Query<&mut Transform, With<Player>>,
+    key_bindings: Res<KeyBindings>,
+    control_settings: Res<ControlSettings>,
+    pointer_target: Res<PointerTarget>,
+    mut player_query: Query<
+        (
+            &Player,
+            &mut Transform,
+            &mut Dash,
+            &mut Velocity,
+            &mut Acceleration,
+        ),
+        Without<RemoteInput>,
+    >,
     time: Res<Time>,
 ) {
-    if let Ok(mut transform) = player_query.get_single_mut() {
-        let mut direction = Vec3::ZERO;
+    let delta = time.delta_seconds();
+
+    for (player, mut transform, mut dash, mut velocity, mut acceleration) in player_query.iter_mut()
+    {
+        // There is only one pointer, so it steers the first player and the rest stay on keys.
+        let scheme = match control_settings.scheme {
+            ControlScheme::Pointer if player.id != 0 => ControlScheme::Direct,
+            scheme => scheme,
+        };
+        let direction = match scheme {
+            ControlScheme::Pointer => pointer_target.position.map_or(Vec2::ZERO, |target| {
+                target - transform.translation.truncate()
+            }),
+            ControlScheme::Direct | ControlScheme::Momentum => {
+                key_bindings.player(player.id).direction(&keyboard_input)
+            }
+        };
 
-        if keyboard_input.pressed(KeyCode::H) {
-            direction += Vec3::new(-1.0, 0.0, 0.0);
+        if direction.length() > 0.0 {
+            dash.direction = direction.normalize();
         }
-        if keyboard_input.pressed(KeyCode::L) {
-            direction += Vec3::new(1.0, 0.0, 0.0);
+
+        // The dash takes over steering until it ends.
+        if dash.dashing() || delta <= 0.0 {
+            continue;
         }
-        if keyboard_input.pressed(KeyCode::K) {
-            direction += Vec3::new(0.0, 1.0, 0.0);
+
+        match scheme {
+            ControlScheme::Direct => {
+                velocity.0 = direction * PLAYER_SPEED;
+            }
+            ControlScheme::Momentum => {
+                let momentum = &control_settings.momentum;
+                acceleration.0 = direction * momentum.acceleration;
+                velocity.0 += acceleration.0 * delta;
+                if direction == Vec2::ZERO {
+                    velocity.0 *= (1.0 - momentum.friction * delta).max(0.0);
+                }
+                velocity.0 = velocity.0.clamp_length_max(momentum.max_speed);
+            }
+            ControlScheme::Pointer => {
+                // Cap the step so the player doesn't overshoot the target.
+                velocity.0 = direction.clamp_length_max(PLAYER_SPEED * delta) / delta;
+            }
         }
-        if keyboard_input.pressed(KeyCode::J) {
-            direction += Vec3::new(0.0, -1.0, 0.0);
+
+        transform.translation += velocity.0.extend(0.0) * delta;
+    }
+}
+
+pub fn player_dash(
+    mut commands: Commands,
+    keyboard_input: Res<Input<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    control_settings: Res<ControlSettings>,
+    mut player_query: Query<(Entity, &Player, &mut Dash, &mut Velocity), Without<RemoteInput>>,
+) {
+    for (player_entity, player, mut dash, mut velocity) in player_query.iter_mut() {
+        let dash_key = key_bindings.player(player.id).dash;
+        if keyboard_input.just_pressed(dash_key) && dash.ready() {
+            dash.start();
+            // Come out of the dash already moving at top speed; the other schemes don't
+            // carry velocity between frames.
+            if control_settings.scheme == ControlScheme::Momentum {
+                velocity.0 = dash.direction * control_settings.momentum.max_speed;
+            }
+            commands
+                .entity(player_entity)
+                .insert(Invulnerable::new(DASH_DURATION));
         }
+    }
+}
 
-        if direction.length() > 0.0 {
-            direction = direction.normalize();
+pub fn dash_movement(
+    mut player_query: Query<(&mut Transform, &mut Dash), With<Player>>,
+    mut particle_event_writer: EventWriter<ParticleEvent>,
+    time: Res<Time>,
+) {
+    for (mut transform, mut dash) in player_query.iter_mut() {
+        dash.duration.tick(time.delta());
+        if !dash.dashing() {
+            dash.cooldown.tick(time.delta());
+            continue;
         }
 
-        transform.translation += direction * PLAYER_SPEED * time.delta_seconds();
+        transform.translation += dash.direction.extend(0.0) * DASH_SPEED * time.delta_seconds();
+        particle_event_writer.send(ParticleEvent {
+            effect: ParticleEffect::DashTrail,
+            position: transform.translation.truncate(),
+            direction: -dash.direction,
+        });
+    }
+}
+
+/// Fades invulnerable players out and removes the invulnerability once it wears off.
+pub fn tick_invulnerability(
+    mut commands: Commands,
+    mut player_query: Query<(Entity, &mut Sprite, Option<&mut Invulnerable>), With<Player>>,
+    time: Res<Time>,
+) {
+    for (player_entity, mut sprite, invulnerable) in player_query.iter_mut() {
+        let Some(mut invulnerable) = invulnerable else {
+            sprite.color.set_a(1.0);
+            continue;
+        };
+
+        invulnerable.timer.tick(time.delta());
+        if invulnerable.timer.finished() {
+            commands.entity(player_entity).remove::<Invulnerable>();
+            sprite.color.set_a(1.0);
+        } else {
+            sprite.color.set_a(DASH_ALPHA);
+        }
     }
 }
 
 pub fn confine_player_movement(
-    mut player_query: Query<&mut Transform, With<Player>>,
+    mut player_query: Query<(&mut Transform, &mut Dash, &mut Velocity), With<Player>>,
     window_query: Query<&Window, With<PrimaryWindow>>,
+    control_settings: Res<ControlSettings>,
 ) {
-    if let Ok(mut player_transform) = player_query.get_single_mut() {
-        let window = window_query.get_single().unwrap();
-        let (x_min, x_max, y_min, y_max) = get_bounds(window, PLAYER_SIZE);
+    let window = window_query.get_single().unwrap();
+    let (x_min, x_max, y_min, y_max) = get_bounds(window, PLAYER_SIZE);
 
+    for (mut player_transform, mut dash, mut velocity) in player_query.iter_mut() {
         let mut translation = player_transform.translation;
 
         // Bound the player x position
@@ -89,31 +343,44 @@ pub fn confine_player_movement(
             translation.y = y_max;
         }
 
+        // A dash stops dead at the arena edge instead of sliding along it.
+        if translation != player_transform.translation && dash.dashing() {
+            let dash_duration = dash.duration.duration();
+            dash.duration.set_elapsed(dash_duration);
+        }
+
+        // Bounce off (or stop at) the walls rather than pushing into them.
+        let restitution = control_settings.momentum.wall_restitution;
+        if translation.x != player_transform.translation.x {
+            velocity.0.x *= -restitution;
+        }
+        if translation.y != player_transform.translation.y {
+            velocity.0.y *= -restitution;
+        }
+
         player_transform.translation = translation;
     }
 }
 
 pub fn player_hit_enemies(
     mut commands: Commands,
-    mut game_over_event_writer: EventWriter<GameOver>,
-    mut player_query: Query<(Entity, &Transform), With<Player>>,
-    enemy_query: Query<&Transform, With<Enemy>>,
-    asset_server: Res<AssetServer>,
-    score: Res<Score>,
+    mut player_death_events: PlayerDeathEvents,
+    player_query: Query<(Entity, &Player, &Transform), Without<Invulnerable>>,
+    enemy_query: Query<(&Transform, &Enemy)>,
 ) {
-    if let Ok((player_entity, player_transform)) = player_query.get_single_mut() {
-        for enemy_transform in enemy_query.iter() {
+    for (player_entity, player, player_transform) in player_query.iter() {
+        for (enemy_transform, enemy) in enemy_query.iter() {
             let distance = player_transform
                 .translation
                 .distance(enemy_transform.translation);
-            if distance < PLAYER_SIZE / 2.0 + ENEMY_SIZE / 2.0 {
-                let sound_effect = asset_server.load("audio/explosionCrunch_000.ogg");
-                commands.spawn(AudioBundle {
-                    source: sound_effect,
-                    ..default()
-                });
+            if distance < PLAYER_SIZE / 2.0 + enemy.size / 2.0 {
                 commands.entity(player_entity).despawn();
-                game_over_event_writer.send(GameOver { score: score.value });
+                player_death_events.send(
+                    player.id,
+                    player_transform.translation.truncate(),
+                    DeathCause::Enemy(enemy.kind),
+                );
+                break;
             }
         }
     }
@@ -121,26 +388,137 @@ pub fn player_hit_enemies(
 
 pub fn player_hit_stars(
     mut commands: Commands,
-    player_query: Query<&Transform, With<Player>>,
+    player_query: Query<(&Player, &Transform)>,
     star_query: Query<(Entity, &Transform), With<Star>>,
-    asset_server: Res<AssetServer>,
+    mut star_pickup_events: StarPickupEvents,
     mut score: ResMut<Score>,
 ) {
-    if let Ok(player_transform) = player_query.get_single() {
-        for (star_entity, star_transform) in star_query.iter() {
+    for (star_entity, star_transform) in star_query.iter() {
+        // The first player touching the star gets it.
+        let collector = player_query.iter().find(|(_, player_transform)| {
             let distance = player_transform
                 .translation
                 .distance(star_transform.translation);
+            distance < PLAYER_SIZE / 2.0 + STAR_SIZE / 2.0
+        });
 
-            if distance < PLAYER_SIZE / 2.0 + STAR_SIZE / 2.0 {
-                score.value += 1;
-                let sound_effect = asset_server.load("audio/laserLarge_000.ogg");
-                commands.spawn(AudioBundle {
-                    source: sound_effect,
-                    ..default()
-                });
-                commands.entity(star_entity).despawn();
+        if let Some((player, _)) = collector {
+            score.add(player.id, 1);
+            star_pickup_events.send(star_transform.translation.truncate());
+            commands.entity(star_entity).despawn();
+        }
+    }
+}
+
+/// Shakes the camera when an enemy that came within the near miss margin moves away again.
+pub fn player_near_miss_enemies(
+    player_query: Query<(Entity, &Transform), With<Player>>,
+    enemy_query: Query<(Entity, &Transform, &Enemy)>,
+    mut camera_event_writer: EventWriter<CameraEvent>,
+    mut close_enemies: Local<HashSet<(Entity, Entity)>>,
+) {
+    if player_query.is_empty() {
+        close_enemies.clear();
+        return;
+    }
+    // Enemies despawned while close, by splitting for one, never move back out of range.
+    close_enemies.retain(|&(player_entity, enemy_entity)| {
+        player_query.contains(player_entity) && enemy_query.contains(enemy_entity)
+    });
+
+    for (player_entity, player_transform) in player_query.iter() {
+        for (enemy_entity, enemy_transform, enemy) in enemy_query.iter() {
+            let distance = player_transform
+                .translation
+                .distance(enemy_transform.translation);
+            let near_miss_distance = PLAYER_SIZE / 2.0 + enemy.size / 2.0 + NEAR_MISS_MARGIN;
+
+            if distance < near_miss_distance {
+                close_enemies.insert((player_entity, enemy_entity));
+            } else if close_enemies.remove(&(player_entity, enemy_entity)) {
+                camera_event_writer.send(CameraEvent::Shake(0.3));
+            }
+        }
+    }
+}
+
+/// Spends a shared life on each fallen co-op player and decides whether the run is over.
+pub fn handle_player_deaths(
+    mut player_died_event_reader: EventReader<PlayerDied>,
+    mut game_over_event_writer: EventWriter<GameOver>,
+    player_query: Query<&Player>,
+    player_mode: Res<PlayerMode>,
+    mut lives: ResMut<Lives>,
+    mut respawns: ResMut<Respawns>,
+    score: Res<Score>,
+) {
+    let mut fallen = HashSet::default();
+    for event in player_died_event_reader.iter() {
+        fallen.insert(event.player);
+    }
+    if fallen.is_empty() {
+        return;
+    }
+
+    // Despawns are deferred, so fallen players are still in the query this frame.
+    let survivors: Vec<usize> = player_query
+        .iter()
+        .map(|player| player.id)
+        .filter(|id| !fallen.contains(id))
+        .collect();
+
+    let game_over = match *player_mode {
+        PlayerMode::Single => Some(None),
+        PlayerMode::Coop => {
+            for &id in &fallen {
+                if lives.remaining > 0 {
+                    lives.remaining -= 1;
+                    respawns
+                        .pending
+                        .push((id, Timer::from_seconds(RESPAWN_DELAY, TimerMode::Once)));
+                }
             }
+            (survivors.is_empty() && respawns.pending.is_empty()).then_some(None)
         }
+        PlayerMode::Versus => match survivors.as_slice() {
+            [] => Some(None),
+            [winner] => Some(Some(*winner)),
+            _ => None,
+        },
+    };
+
+    if let Some(winner) = game_over {
+        game_over_event_writer.send(GameOver {
+            score: score.value,
+            winner,
+        });
+    }
+}
+
+pub fn tick_respawns(
+    mut commands: Commands,
+    mut respawns: ResMut<Respawns>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    game_assets: Res<GameAssets>,
+    player_mode: Res<PlayerMode>,
+    time: Res<Time>,
+) {
+    let window = window_query.get_single().unwrap();
+
+    for (_, timer) in respawns.pending.iter_mut() {
+        timer.tick(time.delta());
+    }
+
+    for (id, _) in respawns
+        .pending
+        .iter()
+        .filter(|(_, timer)| timer.finished())
+    {
+        let position = start_position(window, *id, player_mode.player_count());
+        let player_entity = spawn_player(&mut commands, &game_assets, *id, position);
+        commands
+            .entity(player_entity)
+            .insert(Invulnerable::new(RESPAWN_GRACE));
     }
+    respawns.pending.retain(|(_, timer)| !timer.finished());
 }
@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+use rand::{thread_rng, Error, Rng, RngCore, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+/// Source of all gameplay randomness. A SplitMix64 generator, so its whole state is a single
+/// number that can be saved and restored to replay a run exactly.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct GameRng {
+    state: u64,
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        GameRng::seed_from_u64(thread_rng().gen())
+    }
+}
+
+impl SeedableRng for GameRng {
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        GameRng {
+            state: u64::from_le_bytes(seed),
+        }
+    }
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
@@ -2,20 +2,28 @@ use bevy::prelude::*;
 use resources::*;
 use systems::*;
 
-use crate::AppState;
+use crate::{profile::resources::ActiveProfile, save::resources::ResumeRun, AppState};
 
 pub mod resources;
 mod systems;
 
+const HIGH_SCORES_FILE: &str = "high_scores.ron";
+
 pub struct ScorePlugin;
 
 impl Plugin for ScorePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(AppState::InGame), insert_score)
-            .init_resource::<HighScores>()
-            .add_systems(Update, update_score.run_if(in_state(AppState::InGame)))
-            .add_systems(Update, high_scores_updated)
-            .add_systems(Update, update_high_scores)
-            .add_systems(OnExit(AppState::InGame), remove_score);
+        app.add_systems(
+            OnEnter(AppState::InGame),
+            insert_score.run_if(not(resource_exists::<ResumeRun>())),
+        )
+        .init_resource::<HighScores>()
+        .add_systems(Update, update_score.run_if(in_state(AppState::InGame)))
+        .add_systems(Update, high_scores_updated)
+        .add_systems(
+            Update,
+            update_high_scores.run_if(resource_exists::<ActiveProfile>()),
+        )
+        .add_systems(OnExit(AppState::InGame), remove_score);
     }
 }
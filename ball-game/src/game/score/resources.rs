@@ -1,10 +1,40 @@
-use bevy::prelude::*;
+use bevy::{prelude::*, utils::HashMap};
+use serde::{Deserialize, Serialize};
 
-#[derive(Resource, Default)]
+use super::HIGH_SCORES_FILE;
+use crate::{
+    game::{mode::resources::GameMode, player::MAX_PLAYERS},
+    profile::resources::ActiveProfile,
+    save::files::{read_ron, write_ron},
+};
+
+#[derive(Resource, Default, Clone, Serialize, Deserialize)]
 pub struct Score {
+    /// Team total, including bonuses nobody collected personally.
     pub value: u32,
+    /// Points each player collected, indexed by `Player::id`.
+    pub players: [u32; MAX_PLAYERS],
+}
+
+impl Score {
+    pub fn add(&mut self, player: usize, points: u32) {
+        self.value += points;
+        self.players[player] += points;
+    }
 }
-#[derive(Resource, Default, Debug)]
+
+#[derive(Resource, Default, Debug, Serialize, Deserialize)]
 pub struct HighScores {
-    pub scores: Vec<(String, u32)>,
+    /// A separate table for each game mode, since their scores don't compare.
+    pub tables: HashMap<GameMode, Vec<(String, u32)>>,
+}
+
+impl HighScores {
+    pub fn load(profile: &ActiveProfile) -> Self {
+        read_ron(&profile.path(HIGH_SCORES_FILE)).unwrap_or_default()
+    }
+
+    pub fn save(&self, profile: &ActiveProfile) {
+        write_ron(&profile.path(HIGH_SCORES_FILE), self);
+    }
 }
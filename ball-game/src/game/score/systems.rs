@@ -1,5 +1,10 @@
 use super::resources::*;
-use crate::events::GameOver;
+use crate::{
+    events::GameOver,
+    game::mode::resources::GameMode,
+    localization::{console::PrintLocalized, resources::LocalizedString},
+    profile::resources::ActiveProfile,
+};
 use bevy::prelude::*;
 
 pub fn insert_score(mut commands: Commands) {
@@ -10,23 +15,49 @@ pub fn remove_score(mut commands: Commands) {
     commands.remove_resource::<Score>();
 }
 
-pub fn update_score(score: Res<Score>) {
+pub fn update_score(mut commands: Commands, score: Res<Score>) {
     if score.is_changed() {
-        println!("Score: {}", score.value);
+        commands.add(PrintLocalized(
+            LocalizedString::new("console.score").with_arg("score", score.value),
+        ));
     }
 }
 
 pub fn update_high_scores(
     mut game_over_event_reader: EventReader<GameOver>,
     mut high_scores: ResMut<HighScores>,
+    game_mode: Res<GameMode>,
+    active_profile: Res<ActiveProfile>,
 ) {
     for event in game_over_event_reader.iter() {
-        high_scores.scores.push(("Player".to_string(), event.score));
+        high_scores
+            .tables
+            .entry(*game_mode)
+            .or_default()
+            .push((active_profile.name.clone(), event.score));
     }
 }
 
-pub fn high_scores_updated(high_scores: Res<HighScores>) {
-    if high_scores.is_changed() {
-        println!("HighScores {:?}", high_scores);
+/// Prints each mode's table, best score first.
+pub fn high_scores_updated(mut commands: Commands, high_scores: Res<HighScores>) {
+    if !high_scores.is_changed() {
+        return;
+    }
+    for game_mode in GameMode::ALL {
+        let Some(table) = high_scores.tables.get(&game_mode) else {
+            continue;
+        };
+        let mut entries = table.clone();
+        entries.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+        let scores = entries
+            .iter()
+            .map(|(name, score)| format!("{name} {score}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        commands.add(PrintLocalized(
+            LocalizedString::new("console.high_scores")
+                .with_localized_arg("mode", LocalizedString::new(game_mode.label_key()))
+                .with_arg("scores", scores),
+        ));
     }
 }
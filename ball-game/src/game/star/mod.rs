@@ -1,15 +1,17 @@
 use bevy::prelude::*;
 
-use crate::AppState;
+use crate::{save::resources::ResumeRun, AppState};
 
 use self::{resources::StarSpawnTimer, systems::*};
 
-use super::SimulationState;
+use super::{boss::resources::no_boss_encounter, systems::seed_game_rng, SimulationState};
 
 pub mod components;
 pub mod resources;
 mod systems;
 
+pub use systems::spawn_star;
+
 pub const STAR_SIZE: f32 = 30.0;
 const NUMBER_OF_STARS: usize = 10;
 
@@ -18,12 +20,18 @@ pub struct StarPlugin;
 impl Plugin for StarPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<StarSpawnTimer>()
-            .add_systems(OnEnter(AppState::InGame), spawn_stars)
+            .add_systems(
+                OnEnter(AppState::InGame),
+                spawn_stars
+                    .after(seed_game_rng)
+                    .run_if(not(resource_exists::<ResumeRun>())),
+            )
             .add_systems(
                 Update,
                 (tick_star_spawn_timer, spawn_stars_over_time)
                     .run_if(in_state(AppState::InGame))
-                    .run_if(in_state(SimulationState::Running)),
+                    .run_if(in_state(SimulationState::Running))
+                    .run_if(no_boss_encounter),
             )
             .add_systems(OnExit(AppState::InGame), despawn_stars);
     }
@@ -1,27 +1,47 @@
 use bevy::{prelude::*, window::PrimaryWindow};
-use rand::random;
+use rand::Rng;
 
 use super::{components::Star, resources::StarSpawnTimer, NUMBER_OF_STARS};
+use crate::{
+    game::{
+        level::active::{to_world, ActiveLevel, SpawnZone},
+        resources::GameRng,
+    },
+    loading::resources::GameAssets,
+};
+
+pub fn spawn_star(commands: &mut Commands, game_assets: &GameAssets, position: Vec2) {
+    commands.spawn((
+        SpriteBundle {
+            transform: Transform::from_xyz(position.x, position.y, 0.0),
+            texture: game_assets.star_texture.clone(),
+            ..default()
+        },
+        Star {},
+    ));
+}
 
 pub fn spawn_stars(
     mut commands: Commands,
     window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
+    game_assets: Res<GameAssets>,
+    mut game_rng: ResMut<GameRng>,
+    active_level: ActiveLevel,
 ) {
     let window = window_query.get_single().unwrap();
 
+    if let Some(level) = active_level.get() {
+        for position in level.stars.iter() {
+            spawn_star(&mut commands, &game_assets, to_world(*position, window));
+        }
+        return;
+    }
+
     for _ in 0..NUMBER_OF_STARS {
-        let random_x = random::<f32>() * window.width();
-        let random_y = random::<f32>() * window.height();
-
-        commands.spawn((
-            SpriteBundle {
-                transform: Transform::from_xyz(random_x, random_y, 0.0),
-                texture: asset_server.load("sprites/star.png"),
-                ..default()
-            },
-            Star {},
-        ));
+        let random_x = game_rng.gen::<f32>() * window.width();
+        let random_y = game_rng.gen::<f32>() * window.height();
+
+        spawn_star(&mut commands, &game_assets, Vec2::new(random_x, random_y));
     }
 }
 
@@ -38,21 +58,15 @@ pub fn tick_star_spawn_timer(mut star_spawn_timer: ResMut<StarSpawnTimer>, time:
 pub fn spawn_stars_over_time(
     mut commands: Commands,
     window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
+    game_assets: Res<GameAssets>,
     star_spawn_timer: Res<StarSpawnTimer>,
+    mut game_rng: ResMut<GameRng>,
+    active_level: ActiveLevel,
 ) {
     if star_spawn_timer.timer.finished() {
         let window = window_query.get_single().unwrap();
-        let random_x = random::<f32>() * window.width();
-        let random_y = random::<f32>() * window.height();
-
-        commands.spawn((
-            SpriteBundle {
-                transform: Transform::from_xyz(random_x, random_y, 0.0),
-                texture: asset_server.load("sprites/star.png"),
-                ..default()
-            },
-            Star {},
-        ));
+        let position = active_level.spawn_point(SpawnZone::Stars, window, &mut game_rng);
+
+        spawn_star(&mut commands, &game_assets, position);
     }
 }
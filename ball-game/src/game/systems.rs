@@ -1,6 +1,11 @@
 use bevy::prelude::*;
+use rand::SeedableRng;
 
-use super::SimulationState;
+use super::{
+    mode::resources::{daily_seed, GameMode},
+    resources::GameRng,
+    SimulationState,
+};
 
 pub fn pause_simulation(mut next_simulation_state: ResMut<NextState<SimulationState>>) {
     next_simulation_state.set(SimulationState::Paused);
@@ -24,3 +29,11 @@ pub fn toggle_simulation(
         }
     }
 }
+
+/// Gives every new run its own layout, except on the daily challenge where the date decides.
+pub fn seed_game_rng(mut game_rng: ResMut<GameRng>, game_mode: Res<GameMode>) {
+    *game_rng = match *game_mode {
+        GameMode::DailyChallenge => GameRng::seed_from_u64(daily_seed()),
+        GameMode::Endless | GameMode::TimeAttack => GameRng::default(),
+    };
+}
@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+use systems::interactions::*;
+use systems::layout::*;
+
+use crate::AppState;
+
+mod components;
+mod systems;
+
+const LEVEL_BUTTON_WIDTH: f32 = 360.0;
+
+pub struct LevelSelectPlugin;
+
+impl Plugin for LevelSelectPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::LevelSelect), spawn_level_select_menu)
+            .add_systems(
+                Update,
+                handle_level_select_actions.run_if(in_state(AppState::LevelSelect)),
+            )
+            .add_systems(OnExit(AppState::LevelSelect), despawn_level_select_menu);
+    }
+}
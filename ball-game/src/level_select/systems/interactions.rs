@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+use crate::{
+    events::MenuButtonPressed, game::level::resources::SelectedLevel,
+    widgets::components::MenuAction, AppState,
+};
+
+/// Picks the level for the next run and heads back to the main menu to start it.
+pub fn handle_level_select_actions(
+    mut menu_button_event_reader: EventReader<MenuButtonPressed>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut selected_level: ResMut<SelectedLevel>,
+) {
+    for event in menu_button_event_reader.iter() {
+        if let MenuAction::SelectLevel(level) = event.action {
+            selected_level.level = level;
+            next_app_state.set(AppState::MainMenu);
+        }
+    }
+}
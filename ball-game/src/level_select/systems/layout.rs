@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+
+use crate::{
+    game::level::{asset::Level, resources::SelectedLevel},
+    level_select::{components::*, LEVEL_BUTTON_WIDTH},
+    loading::resources::GameAssets,
+    localization::{components::LocalizedText, resources::LocalizedString},
+    main_menu::styles::{get_main_menu_style, get_title_text_style},
+    theme::{
+        components::{MenuBackground, ThemedText},
+        resources::UiTheme,
+    },
+    widgets::{builder::MenuButtonBuilder, components::MenuAction},
+    AppState,
+};
+
+/// Falls back to the file path for levels that failed to load.
+fn level_label(
+    level: Option<usize>,
+    game_assets: &GameAssets,
+    levels: &Assets<Level>,
+    selected_level: &SelectedLevel,
+) -> LocalizedString {
+    let name = match level {
+        Some(index) => LocalizedString::raw(
+            levels
+                .get(&game_assets.levels[index])
+                .map_or(game_assets.level_path(index), |level| level.name.as_str()),
+        ),
+        None => LocalizedString::new("level_select.open_arena"),
+    };
+    if level == selected_level.level {
+        LocalizedString::new("level_select.selected").with_localized_arg("level", name)
+    } else {
+        name
+    }
+}
+
+pub fn spawn_level_select_menu(
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    ui_theme: Res<UiTheme>,
+    levels: Res<Assets<Level>>,
+    selected_level: Res<SelectedLevel>,
+) {
+    build_level_select_menu(
+        &mut commands,
+        &game_assets,
+        &ui_theme,
+        &levels,
+        &selected_level,
+    );
+}
+
+pub fn despawn_level_select_menu(
+    mut commands: Commands,
+    level_select_menu_query: Query<Entity, With<LevelSelectMenu>>,
+) {
+    if let Ok(level_select_menu_entity) = level_select_menu_query.get_single() {
+        commands
+            .entity(level_select_menu_entity)
+            .despawn_recursive();
+    }
+}
+
+pub fn build_level_select_menu(
+    commands: &mut Commands,
+    game_assets: &GameAssets,
+    ui_theme: &UiTheme,
+    levels: &Assets<Level>,
+    selected_level: &SelectedLevel,
+) -> Entity {
+    let choices: Vec<Option<usize>> = std::iter::once(None)
+        .chain((0..game_assets.levels.len()).map(Some))
+        .collect();
+    let level_count = choices.len() as u32;
+
+    let level_select_menu_entity = commands
+        .spawn((
+            NodeBundle {
+                style: get_main_menu_style(ui_theme),
+                background_color: ui_theme.palette.background.into(),
+                ..default()
+            },
+            MenuBackground {},
+            LevelSelectMenu {},
+        ))
+        .with_children(|parent| {
+            // === Title ===
+            parent.spawn((
+                TextBundle::from_section("", get_title_text_style(ui_theme)),
+                LocalizedText::new(LocalizedString::new("level_select.title")),
+                ThemedText::Title,
+            ));
+            // === Level Buttons ===
+            for (index, level) in choices.into_iter().enumerate() {
+                MenuButtonBuilder::new(
+                    level_label(level, game_assets, levels, selected_level),
+                    MenuAction::SelectLevel(level),
+                    index as u32,
+                )
+                .width(LEVEL_BUTTON_WIDTH)
+                .spawn(parent, ui_theme);
+            }
+            // === Editor Button ===
+            MenuButtonBuilder::new(
+                LocalizedString::new("level_select.edit"),
+                MenuAction::GoTo(AppState::Editor),
+                level_count,
+            )
+            .width(LEVEL_BUTTON_WIDTH)
+            .spawn(parent, ui_theme);
+            // === Back Button ===
+            MenuButtonBuilder::new(
+                LocalizedString::new("common.back"),
+                MenuAction::GoTo(AppState::MainMenu),
+                level_count + 1,
+            )
+            .spawn(parent, ui_theme);
+        })
+        .id();
+
+    level_select_menu_entity
+}
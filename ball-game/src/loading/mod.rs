@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use systems::layout::*;
+use systems::progress::check_asset_loading;
+
+use crate::{
+    game::level::asset::{Level, LevelLoader},
+    localization::asset::{StringTable, StringTableLoader},
+    theme::asset::{Theme, ThemeLoader},
+    AppState,
+};
+
+use self::resources::{FailedAssets, GameAssets};
+
+pub mod components;
+pub mod resources;
+mod styles;
+mod systems;
+
+pub struct LoadingPlugin;
+
+impl Plugin for LoadingPlugin {
+    fn build(&self, app: &mut App) {
+        // The loaders have to exist before `GameAssets` starts loading the files they read.
+        app.add_asset::<Level>()
+            .init_asset_loader::<LevelLoader>()
+            .add_asset::<Theme>()
+            .init_asset_loader::<ThemeLoader>()
+            .add_asset::<StringTable>()
+            .init_asset_loader::<StringTableLoader>()
+            .init_resource::<GameAssets>()
+            .add_systems(OnEnter(AppState::Loading), spawn_loading_screen)
+            .add_systems(
+                Update,
+                (
+                    check_asset_loading.run_if(not(resource_exists::<FailedAssets>())),
+                    (despawn_loading_screen, spawn_error_screen)
+                        .run_if(resource_added::<FailedAssets>()),
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Loading)),
+            )
+            .add_systems(OnExit(AppState::Loading), despawn_loading_screen);
+    }
+}
@@ -0,0 +1,140 @@
+use bevy::{asset::HandleId, prelude::*};
+
+use crate::{
+    audio::components::MusicTrack,
+    game::level::asset::Level,
+    localization::{asset::StringTable, resources::Language},
+    theme::{asset::Theme, resources::ThemeName},
+};
+
+const PLAYER_TEXTURE: &str = "sprites/ball_blue_large.png";
+const ENEMY_TEXTURE: &str = "sprites/ball_red_large.png";
+const STAR_TEXTURE: &str = "sprites/star.png";
+const PLUCK_SOUNDS: [&str; 2] = ["audio/pluck_001.ogg", "audio/pluck_002.ogg"];
+const STAR_SOUND: &str = "audio/laserLarge_000.ogg";
+const EXPLOSION_SOUND: &str = "audio/explosionCrunch_000.ogg";
+const MENU_MUSIC: &str = "audio/music/menu.ogg";
+const GAMEPLAY_MUSIC: &str = "audio/music/gameplay.ogg";
+const GAME_OVER_MUSIC: &str = "audio/music/game_over.ogg";
+const FONT: &str = "fonts/FiraSans-Bold.ttf";
+const LEVELS: [&str; 2] = ["levels/pillars.level.ron", "levels/crossroads.level.ron"];
+/// In `ThemeName` order.
+const THEMES: [&str; 3] = [
+    "themes/dark.theme.ron",
+    "themes/light.theme.ron",
+    "themes/high_contrast.theme.ron",
+];
+/// In `Language` order, English first as the one the others fall back on.
+const LOCALES: [&str; 3] = [
+    "locales/en.locale.ron",
+    "locales/es.locale.ron",
+    "locales/de.locale.ron",
+];
+
+/// Handles to every asset the game uses, loaded once while in `AppState::Loading`.
+#[derive(Resource)]
+pub struct GameAssets {
+    pub player_texture: Handle<Image>,
+    pub enemy_texture: Handle<Image>,
+    pub star_texture: Handle<Image>,
+    pub pluck_sounds: [Handle<AudioSource>; 2],
+    pub star_sound: Handle<AudioSource>,
+    pub explosion_sound: Handle<AudioSource>,
+    pub menu_music: Handle<AudioSource>,
+    pub gameplay_music: Handle<AudioSource>,
+    pub game_over_music: Handle<AudioSource>,
+    pub font: Handle<Font>,
+    /// Listed in the level select, in this order.
+    pub levels: Vec<Handle<Level>>,
+    pub themes: [Handle<Theme>; 3],
+    pub locales: [Handle<StringTable>; 3],
+}
+
+impl FromWorld for GameAssets {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+        GameAssets {
+            player_texture: asset_server.load(PLAYER_TEXTURE),
+            enemy_texture: asset_server.load(ENEMY_TEXTURE),
+            star_texture: asset_server.load(STAR_TEXTURE),
+            pluck_sounds: PLUCK_SOUNDS.map(|path| asset_server.load(path)),
+            star_sound: asset_server.load(STAR_SOUND),
+            explosion_sound: asset_server.load(EXPLOSION_SOUND),
+            menu_music: asset_server.load(MENU_MUSIC),
+            gameplay_music: asset_server.load(GAMEPLAY_MUSIC),
+            game_over_music: asset_server.load(GAME_OVER_MUSIC),
+            font: asset_server.load(FONT),
+            levels: LEVELS.iter().map(|path| asset_server.load(*path)).collect(),
+            themes: THEMES.map(|path| asset_server.load(path)),
+            locales: LOCALES.map(|path| asset_server.load(path)),
+        }
+    }
+}
+
+impl GameAssets {
+    pub fn music(&self, track: MusicTrack) -> Handle<AudioSource> {
+        match track {
+            MusicTrack::Menu => self.menu_music.clone(),
+            MusicTrack::Gameplay => self.gameplay_music.clone(),
+            MusicTrack::GameOver => self.game_over_music.clone(),
+        }
+    }
+
+    pub fn theme(&self, theme: ThemeName) -> &Handle<Theme> {
+        &self.themes[theme as usize]
+    }
+
+    pub fn locale(&self, language: Language) -> &Handle<StringTable> {
+        &self.locales[language as usize]
+    }
+
+    /// Assets the game can't run without; failing to load any of them is fatal.
+    pub fn required(&self) -> Vec<(&'static str, HandleId)> {
+        let mut required = vec![
+            (PLAYER_TEXTURE, self.player_texture.id()),
+            (ENEMY_TEXTURE, self.enemy_texture.id()),
+            (STAR_TEXTURE, self.star_texture.id()),
+            (PLUCK_SOUNDS[0], self.pluck_sounds[0].id()),
+            (PLUCK_SOUNDS[1], self.pluck_sounds[1].id()),
+            (STAR_SOUND, self.star_sound.id()),
+            (EXPLOSION_SOUND, self.explosion_sound.id()),
+            (FONT, self.font.id()),
+            (LOCALES[0], self.locales[0].id()),
+        ];
+        required.extend(
+            THEMES
+                .iter()
+                .zip(self.themes.iter())
+                .map(|(path, theme)| (*path, theme.id())),
+        );
+        required
+    }
+
+    /// Assets the game can run without, only warned about when they fail to load.
+    pub fn optional(&self) -> Vec<(&'static str, HandleId)> {
+        let mut optional = vec![
+            (MENU_MUSIC, self.menu_music.id()),
+            (GAMEPLAY_MUSIC, self.gameplay_music.id()),
+            (GAME_OVER_MUSIC, self.game_over_music.id()),
+            (LOCALES[1], self.locales[1].id()),
+            (LOCALES[2], self.locales[2].id()),
+        ];
+        optional.extend(
+            LEVELS
+                .iter()
+                .zip(self.levels.iter())
+                .map(|(path, level)| (*path, level.id())),
+        );
+        optional
+    }
+
+    pub fn level_path(&self, level: usize) -> &'static str {
+        LEVELS[level]
+    }
+}
+
+/// Paths of required assets that failed to load.
+#[derive(Resource, Default)]
+pub struct FailedAssets {
+    pub paths: Vec<&'static str>,
+}
@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+
+pub const PROGRESS_BAR_BACKGROUND_COLOR: Color = Color::rgb(0.15, 0.15, 0.15);
+pub const PROGRESS_BAR_COLOR: Color = Color::rgb(0.35, 0.75, 0.35);
+
+pub fn get_screen_style() -> Style {
+    Style {
+        width: Val::Percent(100.0),
+        height: Val::Percent(100.0),
+        flex_direction: FlexDirection::Column,
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        row_gap: Val::Px(16.0),
+        ..default()
+    }
+}
+
+pub fn get_progress_bar_background_style() -> Style {
+    Style {
+        width: Val::Px(400.0),
+        height: Val::Px(24.0),
+        ..Style::DEFAULT
+    }
+}
+
+pub fn get_progress_bar_style() -> Style {
+    Style {
+        width: Val::Percent(0.0),
+        height: Val::Percent(100.0),
+        ..Style::DEFAULT
+    }
+}
+
+/// Text shown before the font is loaded falls back to Bevy's default font.
+pub fn get_loading_text_style() -> TextStyle {
+    TextStyle {
+        font_size: 32.0,
+        color: Color::WHITE,
+        ..default()
+    }
+}
+
+pub fn get_error_text_style() -> TextStyle {
+    TextStyle {
+        font_size: 24.0,
+        color: Color::rgb(0.9, 0.3, 0.3),
+        ..default()
+    }
+}
@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+
+use crate::loading::{
+    components::{ErrorScreen, LoadingScreen, ProgressBar},
+    resources::FailedAssets,
+    styles::{
+        get_error_text_style, get_loading_text_style, get_progress_bar_background_style,
+        get_progress_bar_style, get_screen_style, PROGRESS_BAR_BACKGROUND_COLOR,
+        PROGRESS_BAR_COLOR,
+    },
+};
+
+pub fn spawn_loading_screen(mut commands: Commands) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: get_screen_style(),
+                ..default()
+            },
+            LoadingScreen {},
+        ))
+        .with_children(|parent| {
+            // Untranslated, as the string tables are among what's loading.
+            parent.spawn(TextBundle::from_section(
+                "Loading...",
+                get_loading_text_style(),
+            ));
+            // === Progress Bar ===
+            parent
+                .spawn(NodeBundle {
+                    style: get_progress_bar_background_style(),
+                    background_color: PROGRESS_BAR_BACKGROUND_COLOR.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        NodeBundle {
+                            style: get_progress_bar_style(),
+                            background_color: PROGRESS_BAR_COLOR.into(),
+                            ..default()
+                        },
+                        ProgressBar {},
+                    ));
+                });
+        });
+}
+
+pub fn despawn_loading_screen(
+    mut commands: Commands,
+    loading_screen_query: Query<Entity, With<LoadingScreen>>,
+) {
+    if let Ok(loading_screen_entity) = loading_screen_query.get_single() {
+        commands.entity(loading_screen_entity).despawn_recursive();
+    }
+}
+
+pub fn spawn_error_screen(mut commands: Commands, failed_assets: Res<FailedAssets>) {
+    let mut message = String::from("Failed to load assets:\n");
+    for path in failed_assets.paths.iter() {
+        message.push_str(&format!("assets/{path}\n"));
+    }
+    message.push_str("\nPress Escape to quit");
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: get_screen_style(),
+                ..default()
+            },
+            ErrorScreen {},
+        ))
+        .with_children(|parent| {
+            parent.spawn(
+                TextBundle::from_section(message, get_error_text_style())
+                    .with_text_alignment(TextAlignment::Center),
+            );
+        });
+}
@@ -0,0 +1,66 @@
+use bevy::{asset::LoadState, prelude::*};
+
+use crate::{
+    loading::{
+        components::ProgressBar,
+        resources::{FailedAssets, GameAssets},
+    },
+    AppState,
+};
+
+pub fn check_asset_loading(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    game_assets: Res<GameAssets>,
+    mut progress_bar_query: Query<&mut Style, With<ProgressBar>>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    let required = game_assets.required();
+    let optional = game_assets.optional();
+    let total = required.len() + optional.len();
+
+    let mut settled = 0;
+    let mut failed = Vec::new();
+    let mut failed_optional = Vec::new();
+    for (path, id) in required.iter() {
+        match asset_server.get_load_state(*id) {
+            LoadState::Loaded => settled += 1,
+            LoadState::Failed => {
+                settled += 1;
+                failed.push(*path);
+            }
+            _ => {}
+        }
+    }
+    for (path, id) in optional.iter() {
+        match asset_server.get_load_state(*id) {
+            LoadState::Loaded => settled += 1,
+            LoadState::Failed => {
+                settled += 1;
+                failed_optional.push(*path);
+            }
+            _ => {}
+        }
+    }
+
+    if let Ok(mut style) = progress_bar_query.get_single_mut() {
+        style.width = Val::Percent(100.0 * settled as f32 / total as f32);
+    }
+
+    if settled < total {
+        return;
+    }
+
+    for path in failed_optional.iter() {
+        warn!("Optional asset assets/{path} failed to load, continuing without it");
+    }
+
+    if failed.is_empty() {
+        next_app_state.set(AppState::ProfileSelect);
+    } else {
+        for path in failed.iter() {
+            error!("Required asset assets/{path} failed to load");
+        }
+        commands.insert_resource(FailedAssets { paths: failed });
+    }
+}
@@ -0,0 +1,34 @@
+use bevy::{
+    asset::{AssetLoader, LoadContext, LoadedAsset},
+    reflect::{TypePath, TypeUuid},
+    utils::{BoxedFuture, HashMap},
+};
+use serde::Deserialize;
+
+/// Every UI string in one language by key, read from `assets/locales/*.locale.ron`. Values can
+/// hold `{name}` placeholders, filled in from a `LocalizedString`'s arguments.
+#[derive(Debug, Clone, Deserialize, TypeUuid, TypePath)]
+#[uuid = "4c9e2b7a-8f13-4d65-a0b2-7e1f5c3d9a86"]
+#[serde(transparent)]
+pub struct StringTable(pub HashMap<String, String>);
+
+#[derive(Default)]
+pub struct StringTableLoader;
+
+impl AssetLoader for StringTableLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let string_table = ron::de::from_bytes::<StringTable>(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(string_table));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["locale.ron"]
+    }
+}
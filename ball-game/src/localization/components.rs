@@ -0,0 +1,18 @@
+use bevy::prelude::Component;
+
+use super::resources::LocalizedString;
+
+/// Text whose sections are rendered from these strings, one per section, and rendered again
+/// whenever they or the language change.
+#[derive(Component)]
+pub struct LocalizedText {
+    pub sections: Vec<LocalizedString>,
+}
+
+impl LocalizedText {
+    pub fn new(string: LocalizedString) -> Self {
+        LocalizedText {
+            sections: vec![string],
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use bevy::{ecs::system::Command, prelude::*};
+
+use super::resources::{Localization, LocalizedString};
+
+/// Prints a message to the console in the language in use, once commands are applied.
+pub struct PrintLocalized(pub LocalizedString);
+
+impl Command for PrintLocalized {
+    fn apply(self, world: &mut World) {
+        if let Some(localization) = world.get_resource::<Localization>() {
+            println!("{}", localization.format(&self.0));
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use bevy::{prelude::*, ui::UiSystem};
+
+use crate::AppState;
+
+use self::{
+    resources::{LanguageSettings, Localization},
+    systems::*,
+};
+
+pub mod asset;
+pub mod components;
+pub mod console;
+pub mod resources;
+mod systems;
+
+/// UI text in the language picked in the settings.
+pub struct LocalizationPlugin;
+
+impl Plugin for LocalizationPlugin {
+    fn build(&self, app: &mut App) {
+        // The menus need their strings from the moment loading is done.
+        app.init_resource::<LanguageSettings>()
+            .add_systems(OnExit(AppState::Loading), apply_language)
+            .add_systems(
+                Update,
+                apply_language
+                    .run_if(resource_changed::<LanguageSettings>())
+                    .run_if(not(in_state(AppState::Loading))),
+            )
+            .add_systems(
+                PostUpdate,
+                render_localized_text
+                    .before(UiSystem::Layout)
+                    .run_if(resource_exists::<Localization>()),
+            );
+    }
+}
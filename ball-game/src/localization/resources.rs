@@ -0,0 +1,129 @@
+use std::sync::Mutex;
+
+use bevy::{
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+use serde::{Deserialize, Serialize};
+
+use super::asset::StringTable;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+    German,
+}
+
+impl Language {
+    /// In the language itself, so it can be found whatever language is showing.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Spanish => "Español",
+            Language::German => "Deutsch",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            Language::English => Language::Spanish,
+            Language::Spanish => Language::German,
+            Language::German => Language::English,
+        }
+    }
+}
+
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LanguageSettings {
+    pub language: Language,
+}
+
+/// A string table key with the values for its placeholders, or text that isn't translated at
+/// all, like profile names.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LocalizedString {
+    Key {
+        key: &'static str,
+        args: Vec<(&'static str, LocalizedString)>,
+    },
+    Raw(String),
+}
+
+impl LocalizedString {
+    pub fn new(key: &'static str) -> Self {
+        LocalizedString::Key {
+            key,
+            args: Vec::new(),
+        }
+    }
+
+    pub fn raw(text: impl Into<String>) -> Self {
+        LocalizedString::Raw(text.into())
+    }
+
+    pub fn with_arg(self, name: &'static str, value: impl ToString) -> Self {
+        self.with_localized_arg(name, LocalizedString::raw(value.to_string()))
+    }
+
+    /// For placeholders that are themselves translated, like the name of a game mode.
+    pub fn with_localized_arg(mut self, name: &'static str, value: LocalizedString) -> Self {
+        if let LocalizedString::Key { args, .. } = &mut self {
+            args.push((name, value));
+        }
+        self
+    }
+}
+
+/// The string table of the language in use, with English to fall back on.
+#[derive(Resource)]
+pub struct Localization {
+    language: Language,
+    strings: HashMap<String, String>,
+    fallback: HashMap<String, String>,
+    /// Keys already warned about, as text that updates every frame would warn every frame.
+    missing: Mutex<HashSet<&'static str>>,
+}
+
+impl Localization {
+    /// `strings` is `None` when the language's table failed to load.
+    pub fn new(language: Language, strings: Option<&StringTable>, fallback: &StringTable) -> Self {
+        Localization {
+            language,
+            strings: strings.map_or_else(HashMap::default, |strings| strings.0.clone()),
+            fallback: fallback.0.clone(),
+            missing: Mutex::new(HashSet::default()),
+        }
+    }
+
+    pub fn get(&self, key: &'static str) -> String {
+        if let Some(text) = self.strings.get(key) {
+            return text.clone();
+        }
+
+        if self.missing.lock().unwrap().insert(key) {
+            warn!(
+                "No {} string for \"{key}\", falling back to English",
+                self.language.label()
+            );
+        }
+        self.fallback
+            .get(key)
+            .cloned()
+            .unwrap_or_else(|| key.to_string())
+    }
+
+    pub fn format(&self, string: &LocalizedString) -> String {
+        match string {
+            LocalizedString::Key { key, args } => {
+                let mut text = self.get(key);
+                for (name, value) in args {
+                    text = text.replace(&format!("{{{name}}}"), &self.format(value));
+                }
+                text
+            }
+            LocalizedString::Raw(text) => text.clone(),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+use super::{
+    asset::StringTable,
+    components::LocalizedText,
+    resources::{Language, LanguageSettings, Localization},
+};
+use crate::loading::resources::GameAssets;
+
+pub fn apply_language(
+    mut commands: Commands,
+    language_settings: Res<LanguageSettings>,
+    game_assets: Res<GameAssets>,
+    string_tables: Res<Assets<StringTable>>,
+) {
+    let language = language_settings.language;
+    if let Some(fallback) = string_tables.get(game_assets.locale(Language::English)) {
+        let strings = string_tables.get(game_assets.locale(language));
+        commands.insert_resource(Localization::new(language, strings, fallback));
+    }
+}
+
+pub fn render_localized_text(
+    mut text_query: Query<(&mut Text, Ref<LocalizedText>)>,
+    localization: Res<Localization>,
+) {
+    for (mut text, localized_text) in text_query.iter_mut() {
+        if !localization.is_changed() && !localized_text.is_changed() {
+            continue;
+        }
+        for (section, string) in text.sections.iter_mut().zip(&localized_text.sections) {
+            section.value = localization.format(string);
+        }
+    }
+}
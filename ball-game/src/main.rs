@@ -1,20 +1,64 @@
+use achievements::AchievementsPlugin;
+use audio::SoundPlugin;
 use bevy::prelude::*;
+use camera::CameraPlugin;
+use campaign_menu::CampaignMenuPlugin;
+use editor::EditorPlugin;
+use focus::FocusPlugin;
 use game::GamePlugin;
+use level_select::LevelSelectPlugin;
+use loading::LoadingPlugin;
+use localization::LocalizationPlugin;
 use main_menu::MainMenuPlugin;
+use network::NetworkPlugin;
+use profile::ProfilePlugin;
+use save::SavePlugin;
+use settings::SettingsPlugin;
 use systems::*;
+use theme::ThemePlugin;
+use widgets::WidgetsPlugin;
 
+mod achievements;
+mod audio;
+mod camera;
+mod campaign_menu;
+mod editor;
 mod events;
+mod focus;
 mod game;
+mod level_select;
+mod loading;
+mod localization;
 mod main_menu;
+mod network;
+mod profile;
+mod save;
+mod settings;
 mod systems;
+mod theme;
+mod widgets;
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
         .add_state::<AppState>()
+        .add_plugins(SettingsPlugin)
+        .add_plugins(LoadingPlugin)
+        .add_plugins(SoundPlugin)
+        .add_plugins(CameraPlugin)
+        .add_plugins(ThemePlugin)
+        .add_plugins(LocalizationPlugin)
+        .add_plugins(FocusPlugin)
+        .add_plugins(WidgetsPlugin)
         .add_plugins(MainMenuPlugin)
+        .add_plugins(LevelSelectPlugin)
+        .add_plugins(CampaignMenuPlugin)
+        .add_plugins(EditorPlugin)
         .add_plugins(GamePlugin)
-        .add_systems(Startup, spawn_camera)
+        .add_plugins(NetworkPlugin)
+        .add_plugins(SavePlugin)
+        .add_plugins(AchievementsPlugin)
+        .add_plugins(ProfilePlugin)
         .add_systems(Update, exit_game)
         .add_systems(Update, handle_game_over)
         .add_systems(Update, transition_to_game_state)
@@ -25,7 +69,24 @@ fn main() {
 #[derive(States, Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
 pub enum AppState {
     #[default]
+    Loading,
+    /// Picking or creating a profile, right after loading.
+    ProfileSelect,
     MainMenu,
+    LevelSelect,
+    /// Picking a campaign stage.
+    Campaign,
+    /// Editing a level layout.
+    Editor,
+    Settings,
+    KeyBindings,
+    /// Lifetime stats, with the way into achievements.
+    Profile,
+    Achievements,
     InGame,
+    /// Playing in a game hosted on another machine.
+    OnlineClient,
+    /// Between campaign stages, after clearing one.
+    LevelComplete,
     GameOver,
 }
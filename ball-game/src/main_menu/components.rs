@@ -4,7 +4,4 @@ use bevy::prelude::Component;
 pub struct MainMenu;
 
 #[derive(Component)]
-pub struct PlayButton;
-
-#[derive(Component)]
-pub struct QuitButton;
+pub struct GameModeText;
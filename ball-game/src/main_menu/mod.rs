@@ -3,11 +3,15 @@ use bevy::prelude::*;
 use systems::interactions::*;
 use systems::layout::despawn_main_menu;
 use systems::layout::spawn_main_menu;
+use systems::layout::update_game_mode_label;
 
 mod components;
-mod styles;
+pub mod styles;
 mod systems;
 
+/// Wide enough for the longest mode name.
+const GAME_MODE_BUTTON_WIDTH: f32 = 300.0;
+
 pub struct MainMenuPlugin;
 
 impl Plugin for MainMenuPlugin {
@@ -15,7 +19,7 @@ impl Plugin for MainMenuPlugin {
         app.add_systems(OnEnter(AppState::MainMenu), spawn_main_menu)
             .add_systems(
                 Update,
-                (interact_with_play_button, interact_with_quit_button)
+                (handle_main_menu_actions, update_game_mode_label)
                     .run_if(in_state(AppState::MainMenu)),
             )
             .add_systems(OnExit(AppState::MainMenu), despawn_main_menu);
@@ -1,26 +1,24 @@
 use bevy::prelude::*;
 
-pub const NORMAL_BUTTON_COLOR: Color = Color::rgb(0.15, 0.15, 0.15);
-pub const HOVERED_BUTTON_COLOR: Color = Color::rgb(0.25, 0.25, 0.25);
-pub const PRESSED_BUTTON_COLOR: Color = Color::rgb(0.35, 0.75, 0.35);
+use crate::theme::{components::ThemedText, resources::UiTheme};
 
-pub fn get_main_menu_style() -> Style {
+pub fn get_main_menu_style(ui_theme: &UiTheme) -> Style {
     Style {
         width: Val::Percent(100.0),
         height: Val::Percent(100.0),
         flex_direction: FlexDirection::Column,
         justify_content: JustifyContent::Center,
         align_items: AlignItems::Center,
-        row_gap: Val::Px(8.0),
-        column_gap: Val::Px(8.0),
+        row_gap: Val::Px(ui_theme.spacing.gap),
+        column_gap: Val::Px(ui_theme.spacing.gap),
         ..default()
     }
 }
 
-pub fn get_button_style() -> Style {
+pub fn get_button_style(ui_theme: &UiTheme) -> Style {
     Style {
         width: Val::Px(200.0),
-        height: Val::Px(80.0),
+        height: Val::Px(ui_theme.spacing.button_height),
         justify_content: JustifyContent::Center,
         align_items: AlignItems::Center,
         ..Style::DEFAULT
@@ -47,18 +45,35 @@ pub fn get_title_style() -> Style {
     }
 }
 
-pub fn get_title_text_style(asset_server: &Res<AssetServer>) -> TextStyle {
+fn get_text_style(ui_theme: &UiTheme, font_size: f32) -> TextStyle {
     TextStyle {
-        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
-        font_size: 48.0,
-        color: Color::WHITE,
+        font: ui_theme.font.clone(),
+        font_size,
+        color: ui_theme.palette.text,
     }
 }
 
-pub fn get_button_text_style(asset_server: &Res<AssetServer>) -> TextStyle {
-    TextStyle {
-        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
-        font_size: 32.0,
-        color: Color::WHITE,
+pub fn get_title_text_style(ui_theme: &UiTheme) -> TextStyle {
+    get_text_style(ui_theme, ui_theme.font_sizes.title)
+}
+
+pub fn get_button_text_style(ui_theme: &UiTheme) -> TextStyle {
+    get_text_style(ui_theme, ui_theme.font_sizes.button)
+}
+
+pub fn get_body_text_style(ui_theme: &UiTheme) -> TextStyle {
+    get_text_style(ui_theme, ui_theme.font_sizes.body)
+}
+
+pub fn get_detail_text_style(ui_theme: &UiTheme) -> TextStyle {
+    get_text_style(ui_theme, ui_theme.font_sizes.detail)
+}
+
+pub fn get_themed_text_style(ui_theme: &UiTheme, themed_text: ThemedText) -> TextStyle {
+    match themed_text {
+        ThemedText::Title => get_title_text_style(ui_theme),
+        ThemedText::Button => get_button_text_style(ui_theme),
+        ThemedText::Body => get_body_text_style(ui_theme),
+        ThemedText::Detail => get_detail_text_style(ui_theme),
     }
 }
@@ -1,51 +1,47 @@
-use bevy::{app::AppExit, prelude::*};
+use bevy::prelude::*;
 
 use crate::{
-    main_menu::{
-        components::*,
-        styles::{HOVERED_BUTTON_COLOR, NORMAL_BUTTON_COLOR, PRESSED_BUTTON_COLOR},
-    },
+    events::MenuButtonPressed,
+    game::{mode::resources::GameMode, player::resources::PlayerMode},
+    localization::{console::PrintLocalized, resources::LocalizedString},
+    network::resources::{HostSession, NetworkSettings},
+    widgets::components::MenuAction,
     AppState,
 };
 
-type WorldQuery<'a> = (&'a Interaction, &'a mut BackgroundColor);
-
-pub fn interact_with_play_button(
-    mut button_query: Query<WorldQuery, (Changed<Interaction>, With<PlayButton>)>,
+pub fn handle_main_menu_actions(
+    mut commands: Commands,
+    mut menu_button_event_reader: EventReader<MenuButtonPressed>,
     mut next_app_state: ResMut<NextState<AppState>>,
+    mut player_mode: ResMut<PlayerMode>,
+    mut game_mode: ResMut<GameMode>,
+    network_settings: Res<NetworkSettings>,
 ) {
-    if let Ok((interaction, mut background_color)) = button_query.get_single_mut() {
-        match *interaction {
-            Interaction::Pressed => {
-                *background_color = PRESSED_BUTTON_COLOR.into();
-                next_app_state.set(AppState::InGame);
-            }
-            Interaction::Hovered => {
-                *background_color = HOVERED_BUTTON_COLOR.into();
-            }
-            Interaction::None => {
-                *background_color = NORMAL_BUTTON_COLOR.into();
-            }
-        }
-    }
-}
-
-pub fn interact_with_quit_button(
-    mut app_exit_event_writer: EventWriter<AppExit>,
-    mut button_query: Query<WorldQuery, (Changed<Interaction>, With<QuitButton>)>,
-) {
-    if let Ok((interaction, mut background_color)) = button_query.get_single_mut() {
-        match *interaction {
-            Interaction::Pressed => {
-                *background_color = PRESSED_BUTTON_COLOR.into();
-                app_exit_event_writer.send(AppExit);
-            }
-            Interaction::Hovered => {
-                *background_color = HOVERED_BUTTON_COLOR.into();
+    for event in menu_button_event_reader.iter() {
+        match event.action {
+            MenuAction::CycleGameMode => {
+                *game_mode = game_mode.next();
             }
-            Interaction::None => {
-                *background_color = NORMAL_BUTTON_COLOR.into();
+            MenuAction::Play(mode) => {
+                *player_mode = mode;
+                next_app_state.set(AppState::InGame);
             }
+            MenuAction::Host => match HostSession::bind(network_settings.host_address) {
+                Ok(host_session) => {
+                    commands.add(PrintLocalized(
+                        LocalizedString::new("console.hosting")
+                            .with_arg("address", network_settings.host_address),
+                    ));
+                    commands.insert_resource(host_session);
+                    *player_mode = PlayerMode::Coop;
+                    next_app_state.set(AppState::InGame);
+                }
+                Err(error) => error!(
+                    "Failed to host on {}: {error}",
+                    network_settings.host_address
+                ),
+            },
+            _ => {}
         }
     }
 }
@@ -1,15 +1,43 @@
 use bevy::prelude::*;
 
-use crate::main_menu::{
-    components::{MainMenu, PlayButton, QuitButton},
-    styles::{
-        get_button_style, get_button_text_style, get_image_style, get_main_menu_style,
-        get_title_style, get_title_text_style, NORMAL_BUTTON_COLOR,
+use crate::{
+    game::{mode::resources::GameMode, player::resources::PlayerMode},
+    loading::resources::GameAssets,
+    localization::{components::LocalizedText, resources::LocalizedString},
+    main_menu::{
+        components::{GameModeText, MainMenu},
+        styles::{get_image_style, get_main_menu_style, get_title_style, get_title_text_style},
+        GAME_MODE_BUTTON_WIDTH,
     },
+    profile::resources::ActiveProfile,
+    save::resources::SaveGame,
+    theme::{
+        components::{MenuBackground, ThemedText},
+        resources::UiTheme,
+    },
+    widgets::{builder::MenuButtonBuilder, components::MenuAction},
+    AppState,
 };
 
-pub fn spawn_main_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
-    build_main_menu(&mut commands, &asset_server);
+pub fn game_mode_label(game_mode: &GameMode) -> LocalizedString {
+    LocalizedString::new("main_menu.game_mode")
+        .with_localized_arg("mode", LocalizedString::new(game_mode.label_key()))
+}
+
+pub fn spawn_main_menu(
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    ui_theme: Res<UiTheme>,
+    game_mode: Res<GameMode>,
+    active_profile: Res<ActiveProfile>,
+) {
+    build_main_menu(
+        &mut commands,
+        &game_assets,
+        &ui_theme,
+        &game_mode,
+        &active_profile,
+    );
 }
 
 pub fn despawn_main_menu(mut commands: Commands, main_menu_query: Query<Entity, With<MainMenu>>) {
@@ -18,13 +46,23 @@ pub fn despawn_main_menu(mut commands: Commands, main_menu_query: Query<Entity,
     }
 }
 
-pub fn build_main_menu(commands: &mut Commands, asset_server: &Res<AssetServer>) -> Entity {
+pub fn build_main_menu(
+    commands: &mut Commands,
+    game_assets: &GameAssets,
+    ui_theme: &UiTheme,
+    game_mode: &GameMode,
+    active_profile: &ActiveProfile,
+) -> Entity {
+    let has_save = SaveGame::exists(active_profile);
+
     let main_menu_entity = commands
         .spawn((
             NodeBundle {
-                style: get_main_menu_style(),
+                style: get_main_menu_style(ui_theme),
+                background_color: ui_theme.palette.background.into(),
                 ..default()
             },
+            MenuBackground {},
             MainMenu {},
         ))
         .with_children(|parent| {
@@ -38,76 +76,120 @@ pub fn build_main_menu(commands: &mut Commands, asset_server: &Res<AssetServer>)
                     // Image 1
                     parent.spawn(ImageBundle {
                         style: get_image_style(),
-                        image: asset_server.load("sprites/ball_blue_large.png").into(),
+                        image: game_assets.player_texture.clone().into(),
                         ..default()
                     });
                     // Text
-                    parent.spawn(TextBundle {
-                        text: Text {
-                            sections: vec![TextSection::new(
-                                "Bevy Ball Game",
-                                get_title_text_style(asset_server),
-                            )],
-                            alignment: TextAlignment::Center,
+                    parent.spawn((
+                        TextBundle {
+                            text: Text {
+                                sections: vec![TextSection::new(
+                                    "",
+                                    get_title_text_style(ui_theme),
+                                )],
+                                alignment: TextAlignment::Center,
+                                ..default()
+                            },
                             ..default()
                         },
-                        ..default()
-                    });
+                        LocalizedText::new(LocalizedString::new("main_menu.title")),
+                        ThemedText::Title,
+                    ));
                     // Image 2
                     parent.spawn(ImageBundle {
                         style: get_image_style(),
-                        image: asset_server.load("sprites/ball_red_large.png").into(),
+                        image: game_assets.enemy_texture.clone().into(),
                         ..default()
                     });
                 });
+            // === Continue Button ===
+            if has_save {
+                MenuButtonBuilder::new(
+                    LocalizedString::new("main_menu.continue"),
+                    MenuAction::Continue,
+                    0,
+                )
+                .spawn(parent, ui_theme);
+            }
+            // === Game Mode Button ===
+            MenuButtonBuilder::new(game_mode_label(game_mode), MenuAction::CycleGameMode, 1)
+                .width(GAME_MODE_BUTTON_WIDTH)
+                .spawn_with_text(parent, ui_theme, GameModeText {});
             // === Play Button ===
-            parent
-                .spawn((
-                    ButtonBundle {
-                        style: get_button_style(),
-                        background_color: NORMAL_BUTTON_COLOR.into(),
-                        ..default()
-                    },
-                    PlayButton {},
-                ))
-                .with_children(|parent| {
-                    parent.spawn(TextBundle {
-                        text: Text {
-                            sections: vec![TextSection::new(
-                                "Play",
-                                get_button_text_style(asset_server),
-                            )],
-                            alignment: TextAlignment::Center,
-                            ..default()
-                        },
-                        ..default()
-                    });
-                });
+            MenuButtonBuilder::new(
+                LocalizedString::new("main_menu.play"),
+                MenuAction::Play(PlayerMode::Single),
+                2,
+            )
+            .spawn(parent, ui_theme);
+            // === Campaign Button ===
+            MenuButtonBuilder::new(
+                LocalizedString::new("main_menu.campaign"),
+                MenuAction::GoTo(AppState::Campaign),
+                3,
+            )
+            .spawn(parent, ui_theme);
+            // === Co-op Button ===
+            MenuButtonBuilder::new(
+                LocalizedString::new("main_menu.coop"),
+                MenuAction::Play(PlayerMode::Coop),
+                4,
+            )
+            .spawn(parent, ui_theme);
+            // === Versus Button ===
+            MenuButtonBuilder::new(
+                LocalizedString::new("main_menu.versus"),
+                MenuAction::Play(PlayerMode::Versus),
+                5,
+            )
+            .spawn(parent, ui_theme);
+            // === Levels Button ===
+            MenuButtonBuilder::new(
+                LocalizedString::new("main_menu.levels"),
+                MenuAction::GoTo(AppState::LevelSelect),
+                6,
+            )
+            .spawn(parent, ui_theme);
+            // === Host Button ===
+            MenuButtonBuilder::new(LocalizedString::new("main_menu.host"), MenuAction::Host, 7)
+                .spawn(parent, ui_theme);
+            // === Join Button ===
+            MenuButtonBuilder::new(
+                LocalizedString::new("main_menu.join"),
+                MenuAction::GoTo(AppState::OnlineClient),
+                8,
+            )
+            .spawn(parent, ui_theme);
+            // === Settings Button ===
+            MenuButtonBuilder::new(
+                LocalizedString::new("main_menu.settings"),
+                MenuAction::GoTo(AppState::Settings),
+                9,
+            )
+            .spawn(parent, ui_theme);
+            // === Profile Button ===
+            MenuButtonBuilder::new(
+                LocalizedString::new("main_menu.profile"),
+                MenuAction::GoTo(AppState::Profile),
+                10,
+            )
+            .spawn(parent, ui_theme);
             // === Quit Button ===
-            parent
-                .spawn((
-                    ButtonBundle {
-                        style: get_button_style(),
-                        background_color: NORMAL_BUTTON_COLOR.into(),
-                        ..default()
-                    },
-                    QuitButton {},
-                ))
-                .with_children(|parent| {
-                    parent.spawn(TextBundle {
-                        text: Text {
-                            sections: vec![TextSection::new(
-                                "Quit",
-                                get_button_text_style(asset_server),
-                            )],
-                            alignment: TextAlignment::Center,
-                            ..default()
-                        },
-                        ..default()
-                    });
-                });
+            MenuButtonBuilder::new(LocalizedString::new("main_menu.quit"), MenuAction::Quit, 11)
+                .spawn(parent, ui_theme);
         })
         .id();
 
     main_menu_entity
 }
+
+pub fn update_game_mode_label(
+    mut text_query: Query<&mut LocalizedText, With<GameModeText>>,
+    game_mode: Res<GameMode>,
+) {
+    if game_mode.is_changed() {
+        if let Ok(mut text) = text_query.get_single_mut() {
+            text.sections[0] = game_mode_label(&game_mode);
+        }
+    }
+}
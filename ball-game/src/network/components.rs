@@ -0,0 +1,23 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use super::protocol::PlayerInput;
+
+/// Identifies an entity across the host and its client.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NetId(pub u32);
+
+/// Inputs from the client for the player the host simulates on its behalf.
+#[derive(Component, Default)]
+pub struct RemoteInput {
+    pub pending: VecDeque<PlayerInput>,
+}
+
+/// Client-side copy of an entity simulated by the host.
+#[derive(Component)]
+pub struct Replicated;
+
+/// The client's own player, moved locally ahead of the host's snapshots.
+#[derive(Component)]
+pub struct Predicted;
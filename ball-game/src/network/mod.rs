@@ -0,0 +1,191 @@
+use bevy::prelude::*;
+use systems::client::*;
+use systems::host::*;
+
+use crate::{
+    game::{player::PlayerSystemSet, SimulationState},
+    AppState,
+};
+
+use self::resources::{ClientSession, HostSession, NetworkSettings};
+
+pub mod components;
+pub mod protocol;
+pub mod resources;
+mod systems;
+pub mod transport;
+
+pub const DEFAULT_PORT: u16 = 7777;
+/// The player the host's client controls; the host keeps player one.
+const REMOTE_PLAYER: usize = 1;
+/// Seconds between snapshots sent by the host.
+const SNAPSHOT_INTERVAL: f32 = 1.0 / 30.0;
+/// Seconds between connection attempts while joining.
+const CONNECT_INTERVAL: f32 = 0.5;
+/// Seconds of silence after which the other side is considered gone.
+const TIMEOUT: f32 = 5.0;
+/// Snapshots kept around as delta baselines.
+const SNAPSHOT_HISTORY: usize = 64;
+/// Unconfirmed inputs kept for resending and replay; older ones are dropped.
+const MAX_PENDING_INPUTS: usize = 64;
+/// Longest frame a single input may claim, so a stalled client can't teleport.
+const MAX_INPUT_DELTA: f32 = 0.1;
+
+pub struct NetworkPlugin;
+
+impl Plugin for NetworkPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NetworkSettings>()
+            // === Host ===
+            .add_systems(
+                Update,
+                (
+                    (
+                        attach_remote_input,
+                        receive_client_messages,
+                        apply_remote_inputs
+                            .in_set(PlayerSystemSet::Movement)
+                            .run_if(in_state(SimulationState::Running)),
+                    )
+                        .chain(),
+                    assign_net_ids,
+                    send_snapshots.after(PlayerSystemSet::Confinement),
+                )
+                    .run_if(in_state(AppState::InGame))
+                    .run_if(resource_exists::<HostSession>()),
+            )
+            .add_systems(
+                Update,
+                send_game_over.run_if(resource_exists::<HostSession>()),
+            )
+            .add_systems(
+                OnEnter(AppState::MainMenu),
+                close_host_session.run_if(resource_exists::<HostSession>()),
+            )
+            // === Client ===
+            .add_systems(OnEnter(AppState::OnlineClient), open_client_session)
+            .add_systems(
+                Update,
+                (
+                    connect_to_host,
+                    receive_host_messages,
+                    send_player_input,
+                    sync_replicated_entities,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::OnlineClient))
+                    .run_if(resource_exists::<ClientSession>()),
+            )
+            .add_systems(
+                OnExit(AppState::OnlineClient),
+                (
+                    despawn_replicated_entities,
+                    close_client_session.run_if(resource_exists::<ClientSession>()),
+                ),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        net::{Ipv4Addr, SocketAddr, UdpSocket},
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use bevy::{asset::AssetPlugin, utils::HashMap, window::PrimaryWindow};
+
+    use super::*;
+    use crate::{
+        events::GameOver,
+        game::{
+            enemy::components::{Enemy, EnemyKind},
+            player::components::Player,
+            score::resources::Score,
+            star::components::Star,
+        },
+        loading::resources::GameAssets,
+        network::components::{NetId, Replicated},
+        settings::resources::KeyBindings,
+    };
+
+    fn free_localhost_address() -> SocketAddr {
+        let socket = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        socket.local_addr().unwrap()
+    }
+
+    fn headless_app(app_state: AppState) -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default(), NetworkPlugin))
+            .add_state::<AppState>()
+            .add_state::<SimulationState>()
+            .add_event::<GameOver>()
+            .init_resource::<Score>()
+            .init_resource::<Input<KeyCode>>()
+            .init_resource::<KeyBindings>();
+        app.world
+            .resource_mut::<NextState<AppState>>()
+            .set(app_state);
+        app
+    }
+
+    fn positions<F: bevy::ecs::query::ReadOnlyWorldQuery>(app: &mut App) -> HashMap<u32, Vec3> {
+        app.world
+            .query_filtered::<(&NetId, &Transform), F>()
+            .iter(&app.world)
+            .map(|(net_id, transform)| (net_id.0, transform.translation))
+            .collect()
+    }
+
+    #[test]
+    fn client_mirrors_the_host() {
+        let host_address = free_localhost_address();
+
+        let mut host = headless_app(AppState::InGame);
+        host.insert_resource(HostSession::bind(host_address).unwrap());
+        host.world.spawn((
+            Player { id: REMOTE_PLAYER },
+            Transform::from_xyz(300.0, 200.0, 0.0),
+        ));
+        host.world.spawn((
+            Enemy {
+                kind: EnemyKind::Chaser,
+                direction: Vec2::X,
+                speed: 0.0,
+                size: 40.0,
+            },
+            Transform::from_xyz(500.0, 400.0, 0.0),
+        ));
+        host.world
+            .spawn((Star {}, Transform::from_xyz(100.0, 600.0, 0.0)));
+
+        let mut client = headless_app(AppState::OnlineClient);
+        client.insert_resource(NetworkSettings {
+            host_address,
+            server_address: host_address,
+        });
+        client.init_resource::<GameAssets>();
+        client.world.spawn((Window::default(), PrimaryWindow));
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        loop {
+            host.update();
+            client.update();
+
+            let host_positions = positions::<()>(&mut host);
+            let client_positions = positions::<With<Replicated>>(&mut client);
+            if host_positions.len() == 3 && client_positions == host_positions {
+                break;
+            }
+            assert!(
+                Instant::now() < deadline,
+                "client never caught up: host {host_positions:?}, client {client_positions:?}"
+            );
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        let client_session = client.world.resource::<ClientSession>();
+        assert_eq!(client_session.player, Some(REMOTE_PLAYER));
+    }
+}
@@ -0,0 +1,556 @@
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::game::{enemy::archetypes::ARCHETYPES, player::MAX_PLAYERS};
+
+/// Leads every datagram so stray packets from other programs are ignored.
+const PROTOCOL_ID: u32 = 0xBA11_0001;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EntityKind {
+    Player(u8),
+    /// Index into `ARCHETYPES`, so the client can pick the matching color.
+    Enemy {
+        archetype: u8,
+        size: f32,
+    },
+    Star,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EntityState {
+    pub kind: EntityKind,
+    pub position: Vec2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlayerInput {
+    pub sequence: u32,
+    pub direction: Vec2,
+    pub dash: bool,
+    /// Frame time the input was held for, so the host moves the player as far as the client did.
+    pub delta: f32,
+}
+
+/// Everything replicated at one host tick, keyed by `NetId`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Snapshot {
+    pub tick: u32,
+    pub entities: HashMap<u32, EntityState>,
+}
+
+/// A snapshot expressed as the changes from an older one the client already has.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnapshotDelta {
+    pub tick: u32,
+    /// Tick of the snapshot this delta applies to; `None` means it holds every entity.
+    pub baseline: Option<u32>,
+    /// Sequence of the last client input the host has applied.
+    pub last_input: u32,
+    pub score: u32,
+    pub changed: Vec<(u32, EntityState)>,
+    pub removed: Vec<u32>,
+}
+
+impl Snapshot {
+    pub fn delta(&self, baseline: Option<&Snapshot>, last_input: u32, score: u32) -> SnapshotDelta {
+        let mut changed: Vec<(u32, EntityState)> = self
+            .entities
+            .iter()
+            .filter(|(net_id, state)| {
+                baseline.and_then(|baseline| baseline.entities.get(*net_id)) != Some(*state)
+            })
+            .map(|(net_id, state)| (*net_id, *state))
+            .collect();
+        changed.sort_by_key(|(net_id, _)| *net_id);
+
+        let mut removed: Vec<u32> = baseline
+            .map(|baseline| {
+                baseline
+                    .entities
+                    .keys()
+                    .filter(|net_id| !self.entities.contains_key(*net_id))
+                    .copied()
+                    .collect()
+            })
+            .unwrap_or_default();
+        removed.sort();
+
+        SnapshotDelta {
+            tick: self.tick,
+            baseline: baseline.map(|baseline| baseline.tick),
+            last_input,
+            score,
+            changed,
+            removed,
+        }
+    }
+
+    /// Rebuilds the full snapshot from a delta and the baseline it was made against.
+    pub fn apply(baseline: Option<&Snapshot>, delta: &SnapshotDelta) -> Snapshot {
+        let mut entities = baseline
+            .map(|baseline| baseline.entities.clone())
+            .unwrap_or_default();
+        for net_id in &delta.removed {
+            entities.remove(net_id);
+        }
+        for (net_id, state) in &delta.changed {
+            entities.insert(*net_id, *state);
+        }
+
+        Snapshot {
+            tick: delta.tick,
+            entities,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Message {
+    /// Client asking to join, resent until accepted.
+    Connect,
+    Accept {
+        player: u8,
+    },
+    /// Every input the host hasn't confirmed yet, so a lost packet costs nothing.
+    Input {
+        /// Latest snapshot tick the client has received.
+        ack: Option<u32>,
+        inputs: Vec<PlayerInput>,
+    },
+    Snapshot(SnapshotDelta),
+    GameOver {
+        score: u32,
+        winner: Option<u8>,
+    },
+    Disconnect,
+}
+
+impl Message {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut writer = Writer::default();
+        writer.u32(PROTOCOL_ID);
+
+        match self {
+            Message::Connect => writer.u8(0),
+            Message::Accept { player } => {
+                writer.u8(1);
+                writer.u8(*player);
+            }
+            Message::Input { ack, inputs } => {
+                writer.u8(2);
+                writer.option_u32(*ack);
+                writer.u16(inputs.len() as u16);
+                for input in inputs {
+                    writer.u32(input.sequence);
+                    writer.vec2(input.direction);
+                    writer.bool(input.dash);
+                    writer.f32(input.delta);
+                }
+            }
+            Message::Snapshot(delta) => {
+                writer.u8(3);
+                writer.u32(delta.tick);
+                writer.option_u32(delta.baseline);
+                writer.u32(delta.last_input);
+                writer.u32(delta.score);
+                writer.u16(delta.changed.len() as u16);
+                for (net_id, state) in &delta.changed {
+                    writer.u32(*net_id);
+                    writer.entity_kind(state.kind);
+                    writer.vec2(state.position);
+                }
+                writer.u16(delta.removed.len() as u16);
+                for net_id in &delta.removed {
+                    writer.u32(*net_id);
+                }
+            }
+            Message::GameOver { score, winner } => {
+                writer.u8(4);
+                writer.u32(*score);
+                writer.bool(winner.is_some());
+                writer.u8(winner.unwrap_or_default());
+            }
+            Message::Disconnect => writer.u8(5),
+        }
+
+        writer.bytes
+    }
+
+    /// Returns `None` for anything that isn't a complete, well-formed message.
+    pub fn decode(bytes: &[u8]) -> Option<Message> {
+        let mut reader = Reader { bytes };
+        if reader.u32()? != PROTOCOL_ID {
+            return None;
+        }
+
+        let message = match reader.u8()? {
+            0 => Message::Connect,
+            1 => Message::Accept {
+                player: reader.player_id()?,
+            },
+            2 => {
+                let ack = reader.option_u32()?;
+                let count = reader.u16()?;
+                let mut inputs = Vec::with_capacity(count as usize);
+                for _ in 0..count {
+                    inputs.push(PlayerInput {
+                        sequence: reader.u32()?,
+                        direction: reader.vec2()?,
+                        dash: reader.bool()?,
+                        delta: reader.f32()?,
+                    });
+                }
+                Message::Input { ack, inputs }
+            }
+            3 => {
+                let tick = reader.u32()?;
+                let baseline = reader.option_u32()?;
+                let last_input = reader.u32()?;
+                let score = reader.u32()?;
+                let changed_count = reader.u16()?;
+                let mut changed = Vec::with_capacity(changed_count as usize);
+                for _ in 0..changed_count {
+                    let net_id = reader.u32()?;
+                    let kind = reader.entity_kind()?;
+                    let position = reader.vec2()?;
+                    changed.push((net_id, EntityState { kind, position }));
+                }
+                let removed_count = reader.u16()?;
+                let mut removed = Vec::with_capacity(removed_count as usize);
+                for _ in 0..removed_count {
+                    removed.push(reader.u32()?);
+                }
+                Message::Snapshot(SnapshotDelta {
+                    tick,
+                    baseline,
+                    last_input,
+                    score,
+                    changed,
+                    removed,
+                })
+            }
+            4 => {
+                let score = reader.u32()?;
+                let has_winner = reader.bool()?;
+                let winner = reader.player_id()?;
+                Message::GameOver {
+                    score,
+                    winner: has_winner.then_some(winner),
+                }
+            }
+            5 => Message::Disconnect,
+            _ => return None,
+        };
+
+        reader.bytes.is_empty().then_some(message)
+    }
+}
+
+#[derive(Default)]
+struct Writer {
+    bytes: Vec<u8>,
+}
+
+impl Writer {
+    fn u8(&mut self, value: u8) {
+        self.bytes.push(value);
+    }
+
+    fn u16(&mut self, value: u16) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn f32(&mut self, value: f32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn bool(&mut self, value: bool) {
+        self.u8(value as u8);
+    }
+
+    fn vec2(&mut self, value: Vec2) {
+        self.f32(value.x);
+        self.f32(value.y);
+    }
+
+    fn option_u32(&mut self, value: Option<u32>) {
+        self.bool(value.is_some());
+        self.u32(value.unwrap_or_default());
+    }
+
+    fn entity_kind(&mut self, kind: EntityKind) {
+        match kind {
+            EntityKind::Player(id) => {
+                self.u8(0);
+                self.u8(id);
+            }
+            EntityKind::Enemy { archetype, size } => {
+                self.u8(1);
+                self.u8(archetype);
+                self.f32(size);
+            }
+            EntityKind::Star => self.u8(2),
+        }
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> Option<[u8; N]> {
+        if self.bytes.len() < N {
+            return None;
+        }
+        let (head, rest) = self.bytes.split_at(N);
+        self.bytes = rest;
+        head.try_into().ok()
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take::<1>().map(|bytes| bytes[0])
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        self.take().map(u16::from_le_bytes)
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take().map(u32::from_le_bytes)
+    }
+
+    fn f32(&mut self) -> Option<f32> {
+        self.take().map(f32::from_le_bytes)
+    }
+
+    fn bool(&mut self) -> Option<bool> {
+        match self.u8()? {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+
+    fn vec2(&mut self) -> Option<Vec2> {
+        Some(Vec2::new(self.f32()?, self.f32()?))
+    }
+
+    fn option_u32(&mut self) -> Option<Option<u32>> {
+        let is_some = self.bool()?;
+        let value = self.u32()?;
+        Some(is_some.then_some(value))
+    }
+
+    /// Player ids and archetypes index into local tables, so out of range ones are malformed.
+    fn player_id(&mut self) -> Option<u8> {
+        self.u8().filter(|&id| (id as usize) < MAX_PLAYERS)
+    }
+
+    fn archetype(&mut self) -> Option<u8> {
+        self.u8()
+            .filter(|&archetype| (archetype as usize) < ARCHETYPES.len())
+    }
+
+    fn entity_kind(&mut self) -> Option<EntityKind> {
+        match self.u8()? {
+            0 => Some(EntityKind::Player(self.player_id()?)),
+            1 => Some(EntityKind::Enemy {
+                archetype: self.archetype()?,
+                size: self.f32()?,
+            }),
+            2 => Some(EntityKind::Star),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(tick: u32, entities: &[(u32, EntityKind, Vec2)]) -> Snapshot {
+        Snapshot {
+            tick,
+            entities: entities
+                .iter()
+                .map(|(net_id, kind, position)| {
+                    (
+                        *net_id,
+                        EntityState {
+                            kind: *kind,
+                            position: *position,
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn messages_survive_encoding() {
+        let enemy = EntityKind::Enemy {
+            archetype: 3,
+            size: 38.4,
+        };
+        let messages = [
+            Message::Connect,
+            Message::Accept { player: 1 },
+            Message::Input {
+                ack: Some(41),
+                inputs: vec![
+                    PlayerInput {
+                        sequence: 7,
+                        direction: Vec2::new(-1.0, 0.0),
+                        dash: false,
+                        delta: 1.0 / 60.0,
+                    },
+                    PlayerInput {
+                        sequence: 8,
+                        direction: Vec2::new(0.6, 0.8),
+                        dash: true,
+                        delta: 1.0 / 144.0,
+                    },
+                ],
+            },
+            Message::Input {
+                ack: None,
+                inputs: Vec::new(),
+            },
+            Message::Snapshot(SnapshotDelta {
+                tick: 12,
+                baseline: Some(9),
+                last_input: 8,
+                score: 30,
+                changed: vec![
+                    (
+                        1,
+                        EntityState {
+                            kind: EntityKind::Player(0),
+                            position: Vec2::new(10.0, 20.0),
+                        },
+                    ),
+                    (
+                        5,
+                        EntityState {
+                            kind: enemy,
+                            position: Vec2::new(-3.5, 700.25),
+                        },
+                    ),
+                ],
+                removed: vec![2, 3],
+            }),
+            Message::GameOver {
+                score: 99,
+                winner: None,
+            },
+            Message::GameOver {
+                score: 12,
+                winner: Some(1),
+            },
+            Message::Disconnect,
+        ];
+
+        for message in messages {
+            assert_eq!(Message::decode(&message.encode()), Some(message));
+        }
+    }
+
+    #[test]
+    fn malformed_datagrams_are_rejected() {
+        let encoded = Message::Accept { player: 1 }.encode();
+
+        assert_eq!(Message::decode(&[]), None);
+        assert_eq!(Message::decode(&encoded[..encoded.len() - 1]), None);
+
+        let mut padded = encoded.clone();
+        padded.push(0);
+        assert_eq!(Message::decode(&padded), None);
+
+        let mut foreign = encoded.clone();
+        foreign[0] ^= 0xFF;
+        assert_eq!(Message::decode(&foreign), None);
+    }
+
+    #[test]
+    fn out_of_range_indices_are_rejected() {
+        let unknown_player = Message::Accept {
+            player: MAX_PLAYERS as u8,
+        };
+        assert_eq!(Message::decode(&unknown_player.encode()), None);
+
+        for kind in [
+            EntityKind::Player(MAX_PLAYERS as u8),
+            EntityKind::Enemy {
+                archetype: ARCHETYPES.len() as u8,
+                size: 30.0,
+            },
+        ] {
+            let delta = snapshot(1, &[(1, kind, Vec2::ZERO)]).delta(None, 0, 0);
+            assert_eq!(Message::decode(&Message::Snapshot(delta).encode()), None);
+        }
+
+        let unknown_winner = Message::GameOver {
+            score: 5,
+            winner: Some(MAX_PLAYERS as u8),
+        };
+        assert_eq!(Message::decode(&unknown_winner.encode()), None);
+    }
+
+    #[test]
+    fn delta_rebuilds_the_snapshot() {
+        let baseline = snapshot(
+            3,
+            &[
+                (1, EntityKind::Player(0), Vec2::new(100.0, 100.0)),
+                (2, EntityKind::Star, Vec2::new(50.0, 60.0)),
+                (3, EntityKind::Star, Vec2::new(70.0, 80.0)),
+            ],
+        );
+        let current = snapshot(
+            5,
+            &[
+                (1, EntityKind::Player(0), Vec2::new(110.0, 100.0)),
+                (3, EntityKind::Star, Vec2::new(70.0, 80.0)),
+                (4, EntityKind::Player(1), Vec2::new(300.0, 200.0)),
+            ],
+        );
+
+        let delta = current.delta(Some(&baseline), 17, 6);
+
+        assert_eq!(delta.baseline, Some(3));
+        // The unchanged star isn't resent.
+        assert_eq!(
+            delta
+                .changed
+                .iter()
+                .map(|(net_id, _)| *net_id)
+                .collect::<Vec<_>>(),
+            vec![1, 4]
+        );
+        assert_eq!(delta.removed, vec![2]);
+        assert_eq!(Snapshot::apply(Some(&baseline), &delta), current);
+    }
+
+    #[test]
+    fn delta_without_baseline_holds_everything() {
+        let current = snapshot(
+            1,
+            &[
+                (1, EntityKind::Player(0), Vec2::new(1.0, 2.0)),
+                (2, EntityKind::Star, Vec2::new(3.0, 4.0)),
+            ],
+        );
+
+        let delta = current.delta(None, 0, 0);
+
+        assert_eq!(delta.baseline, None);
+        assert_eq!(delta.changed.len(), 2);
+        assert!(delta.removed.is_empty());
+        assert_eq!(Snapshot::apply(None, &delta), current);
+    }
+}
@@ -0,0 +1,104 @@
+use std::{
+    collections::VecDeque,
+    env, io,
+    net::{Ipv4Addr, SocketAddr},
+};
+
+use bevy::prelude::*;
+
+use super::{
+    protocol::{PlayerInput, Snapshot},
+    transport::NetSocket,
+    CONNECT_INTERVAL, DEFAULT_PORT, SNAPSHOT_INTERVAL,
+};
+
+#[derive(Resource, Debug, Clone)]
+pub struct NetworkSettings {
+    pub host_address: SocketAddr,
+    /// Where "Join" connects to; set `BALL_GAME_SERVER` (e.g. `192.168.1.20:7777`) to override.
+    pub server_address: SocketAddr,
+}
+
+impl Default for NetworkSettings {
+    fn default() -> Self {
+        let server_address = env::var("BALL_GAME_SERVER")
+            .ok()
+            .and_then(|address| address.parse().ok())
+            .unwrap_or(SocketAddr::from((Ipv4Addr::LOCALHOST, DEFAULT_PORT)));
+
+        NetworkSettings {
+            host_address: SocketAddr::from((Ipv4Addr::UNSPECIFIED, DEFAULT_PORT)),
+            server_address,
+        }
+    }
+}
+
+pub struct RemoteClient {
+    pub address: SocketAddr,
+    pub player: usize,
+    /// Latest snapshot the client confirmed, used as the delta baseline.
+    pub acked_tick: Option<u32>,
+    pub last_input: u32,
+    pub since_heard: f32,
+}
+
+/// Present while this machine hosts an online game.
+#[derive(Resource)]
+pub struct HostSession {
+    pub socket: NetSocket,
+    pub client: Option<RemoteClient>,
+    pub tick: u32,
+    /// Recently sent snapshots, to diff against whichever one the client has.
+    pub history: VecDeque<Snapshot>,
+    pub next_net_id: u32,
+    pub snapshot_timer: Timer,
+}
+
+impl HostSession {
+    pub fn bind(address: SocketAddr) -> io::Result<Self> {
+        Ok(HostSession {
+            socket: NetSocket::bind(address)?,
+            client: None,
+            tick: 0,
+            history: VecDeque::new(),
+            next_net_id: 0,
+            snapshot_timer: Timer::from_seconds(SNAPSHOT_INTERVAL, TimerMode::Repeating),
+        })
+    }
+}
+
+/// Present while this machine plays in someone else's online game.
+#[derive(Resource)]
+pub struct ClientSession {
+    pub socket: NetSocket,
+    pub server: SocketAddr,
+    pub player: Option<usize>,
+    pub next_sequence: u32,
+    /// Inputs the host hasn't applied yet, replayed on top of every snapshot.
+    pub pending_inputs: VecDeque<PlayerInput>,
+    /// Recently received snapshots, newest last, which later deltas build on.
+    pub history: VecDeque<Snapshot>,
+    pub score: u32,
+    pub connect_timer: Timer,
+    pub since_heard: f32,
+}
+
+impl ClientSession {
+    pub fn connect(server: SocketAddr) -> io::Result<Self> {
+        Ok(ClientSession {
+            socket: NetSocket::bind(SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)))?,
+            server,
+            player: None,
+            next_sequence: 0,
+            pending_inputs: VecDeque::new(),
+            history: VecDeque::new(),
+            score: 0,
+            connect_timer: Timer::from_seconds(CONNECT_INTERVAL, TimerMode::Repeating),
+            since_heard: 0.0,
+        })
+    }
+
+    pub fn latest(&self) -> Option<&Snapshot> {
+        self.history.back()
+    }
+}
@@ -0,0 +1,281 @@
+use bevy::{prelude::*, utils::HashSet, window::PrimaryWindow};
+
+use crate::{
+    events::GameOver,
+    game::{
+        enemy::archetypes::ARCHETYPES,
+        player::{PLAYER_COLORS, PLAYER_SIZE, PLAYER_SPEED},
+    },
+    loading::resources::GameAssets,
+    localization::{console::PrintLocalized, resources::LocalizedString},
+    network::{
+        components::{NetId, Predicted, Replicated},
+        protocol::{EntityKind, Message, PlayerInput, Snapshot},
+        resources::{ClientSession, NetworkSettings},
+        MAX_INPUT_DELTA, MAX_PENDING_INPUTS, SNAPSHOT_HISTORY, TIMEOUT,
+    },
+    settings::resources::KeyBindings,
+    systems::get_bounds,
+    AppState,
+};
+
+/// Same movement the host applies in `apply_remote_inputs`, followed by the arena clamp.
+fn predict(position: Vec2, input: &PlayerInput, window: &Window) -> Vec2 {
+    let (x_min, x_max, y_min, y_max) = get_bounds(window, PLAYER_SIZE);
+    let delta = input.delta.clamp(0.0, MAX_INPUT_DELTA);
+    let position = position + input.direction.normalize_or_zero() * PLAYER_SPEED * delta;
+    position.clamp(Vec2::new(x_min, y_min), Vec2::new(x_max, y_max))
+}
+
+pub fn open_client_session(
+    mut commands: Commands,
+    network_settings: Res<NetworkSettings>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    match ClientSession::connect(network_settings.server_address) {
+        Ok(client_session) => {
+            commands.add(PrintLocalized(
+                LocalizedString::new("console.joining")
+                    .with_arg("address", network_settings.server_address),
+            ));
+            commands.insert_resource(client_session);
+        }
+        Err(error) => {
+            error!("Failed to open a socket: {error}");
+            next_app_state.set(AppState::MainMenu);
+        }
+    }
+}
+
+pub fn close_client_session(mut commands: Commands, client_session: Res<ClientSession>) {
+    client_session
+        .socket
+        .send(&Message::Disconnect, client_session.server);
+    commands.remove_resource::<ClientSession>();
+}
+
+pub fn connect_to_host(mut client_session: ResMut<ClientSession>, time: Res<Time>) {
+    if client_session.player.is_some() {
+        return;
+    }
+
+    client_session.connect_timer.tick(time.raw_delta());
+    if client_session.connect_timer.just_finished() {
+        client_session
+            .socket
+            .send(&Message::Connect, client_session.server);
+    }
+}
+
+pub fn receive_host_messages(
+    mut commands: Commands,
+    mut client_session: ResMut<ClientSession>,
+    mut game_over_event_writer: EventWriter<GameOver>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    time: Res<Time>,
+) {
+    client_session.since_heard += time.raw_delta_seconds();
+    if client_session.player.is_some() && client_session.since_heard > TIMEOUT {
+        commands.add(PrintLocalized(LocalizedString::new(
+            "console.lost_connection",
+        )));
+        next_app_state.set(AppState::MainMenu);
+        return;
+    }
+
+    let messages = client_session.socket.receive();
+    for (address, message) in messages {
+        if address != client_session.server {
+            continue;
+        }
+        client_session.since_heard = 0.0;
+
+        match message {
+            Message::Accept { player } => {
+                if client_session.player.is_none() {
+                    commands.add(PrintLocalized(
+                        LocalizedString::new("console.joined").with_arg("player", player + 1),
+                    ));
+                }
+                client_session.player = Some(player as usize);
+            }
+            Message::Snapshot(delta) => {
+                // Out of order; a newer snapshot already arrived.
+                if client_session
+                    .latest()
+                    .is_some_and(|latest| latest.tick >= delta.tick)
+                {
+                    continue;
+                }
+                let baseline = match delta.baseline {
+                    Some(tick) => match client_session
+                        .history
+                        .iter()
+                        .find(|snapshot| snapshot.tick == tick)
+                    {
+                        Some(baseline) => Some(baseline),
+                        // Can't rebuild it; the next one will be against a newer ack.
+                        None => continue,
+                    },
+                    None => None,
+                };
+                let snapshot = Snapshot::apply(baseline, &delta);
+
+                client_session.history.push_back(snapshot);
+                if client_session.history.len() > SNAPSHOT_HISTORY {
+                    client_session.history.pop_front();
+                }
+                if delta.score != client_session.score {
+                    commands.add(PrintLocalized(
+                        LocalizedString::new("console.score").with_arg("score", delta.score),
+                    ));
+                    client_session.score = delta.score;
+                }
+                client_session
+                    .pending_inputs
+                    .retain(|input| input.sequence > delta.last_input);
+            }
+            Message::GameOver { score, winner } => {
+                game_over_event_writer.send(GameOver {
+                    score,
+                    winner: winner.map(|winner| winner as usize),
+                });
+            }
+            Message::Disconnect => {
+                commands.add(PrintLocalized(LocalizedString::new("console.host_ended")));
+                next_app_state.set(AppState::MainMenu);
+            }
+            Message::Connect | Message::Input { .. } => {}
+        }
+    }
+}
+
+/// Sends this frame's input and applies it to the local player straight away.
+pub fn send_player_input(
+    mut client_session: ResMut<ClientSession>,
+    mut predicted_query: Query<&mut Transform, With<Predicted>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    keyboard_input: Res<Input<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    time: Res<Time>,
+) {
+    if client_session.player.is_none() {
+        return;
+    }
+
+    // Whoever joins plays with the first player's keys on their own machine.
+    let bindings = key_bindings.player(0);
+    client_session.next_sequence += 1;
+    let input = PlayerInput {
+        sequence: client_session.next_sequence,
+        direction: bindings.direction(&keyboard_input),
+        dash: keyboard_input.just_pressed(bindings.dash),
+        delta: time.delta_seconds(),
+    };
+
+    client_session.pending_inputs.push_back(input);
+    if client_session.pending_inputs.len() > MAX_PENDING_INPUTS {
+        client_session.pending_inputs.pop_front();
+    }
+    let message = Message::Input {
+        ack: client_session.latest().map(|snapshot| snapshot.tick),
+        inputs: client_session.pending_inputs.iter().copied().collect(),
+    };
+    client_session.socket.send(&message, client_session.server);
+
+    let window = window_query.get_single().unwrap();
+    if let Ok(mut transform) = predicted_query.get_single_mut() {
+        let position = predict(transform.translation.truncate(), &input, window);
+        transform.translation = position.extend(0.0);
+    }
+}
+
+/// Mirrors the latest snapshot: spawns, moves and despawns sprites to match the host.
+pub fn sync_replicated_entities(
+    mut commands: Commands,
+    client_session: Res<ClientSession>,
+    mut replicated_query: Query<(Entity, &NetId, &mut Transform), With<Replicated>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    game_assets: Res<GameAssets>,
+    mut last_synced_tick: Local<Option<u32>>,
+) {
+    let Some(snapshot) = client_session.latest() else {
+        return;
+    };
+    if *last_synced_tick == Some(snapshot.tick) {
+        return;
+    }
+    *last_synced_tick = Some(snapshot.tick);
+
+    let window = window_query.get_single().unwrap();
+    let mut existing = HashSet::default();
+
+    for (entity, net_id, mut transform) in replicated_query.iter_mut() {
+        let Some(state) = snapshot.entities.get(&net_id.0) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        existing.insert(net_id.0);
+
+        let mut position = state.position;
+        // Reconcile: start from where the host had us and redo what it hasn't seen yet.
+        let local_player = client_session
+            .player
+            .map(|player| EntityKind::Player(player as u8));
+        if local_player == Some(state.kind) {
+            for input in &client_session.pending_inputs {
+                position = predict(position, input, window);
+            }
+        }
+        transform.translation = position.extend(0.0);
+    }
+
+    for (net_id, state) in snapshot.entities.iter() {
+        if existing.contains(net_id) {
+            continue;
+        }
+
+        let (texture, color, custom_size) = match state.kind {
+            EntityKind::Player(id) => (
+                game_assets.player_texture.clone(),
+                PLAYER_COLORS[id as usize],
+                None,
+            ),
+            EntityKind::Enemy { archetype, size } => (
+                game_assets.enemy_texture.clone(),
+                ARCHETYPES[archetype as usize].color,
+                Some(Vec2::splat(size)),
+            ),
+            EntityKind::Star => (game_assets.star_texture.clone(), Color::WHITE, None),
+        };
+
+        let mut entity_commands = commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size,
+                    ..default()
+                },
+                transform: Transform::from_translation(state.position.extend(0.0)),
+                texture,
+                ..default()
+            },
+            NetId(*net_id),
+            Replicated {},
+        ));
+        if let EntityKind::Player(id) = state.kind {
+            if client_session.player == Some(id as usize) {
+                entity_commands.insert(Predicted {});
+            }
+        }
+    }
+}
+
+pub fn despawn_replicated_entities(
+    mut commands: Commands,
+    replicated_query: Query<Entity, With<Replicated>>,
+) {
+    for entity in replicated_query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -0,0 +1,261 @@
+use bevy::prelude::*;
+
+use crate::{
+    events::GameOver,
+    game::{
+        enemy::{archetypes::ARCHETYPES, components::Enemy},
+        player::{
+            components::{Dash, Invulnerable, Player},
+            PLAYER_SPEED,
+        },
+        score::resources::Score,
+        star::components::Star,
+    },
+    localization::{console::PrintLocalized, resources::LocalizedString},
+    network::{
+        components::{NetId, RemoteInput},
+        protocol::{EntityKind, EntityState, Message, Snapshot},
+        resources::{HostSession, RemoteClient},
+        MAX_INPUT_DELTA, MAX_PENDING_INPUTS, REMOTE_PLAYER, SNAPSHOT_HISTORY, TIMEOUT,
+    },
+};
+
+type Replicable = Or<(With<Player>, With<Enemy>, With<Star>)>;
+
+type ReplicatedState<'a> = (
+    &'a NetId,
+    &'a Transform,
+    Option<&'a Player>,
+    Option<&'a Enemy>,
+);
+
+/// Hands the client's player over to network input, including after a respawn.
+pub fn attach_remote_input(
+    mut commands: Commands,
+    player_query: Query<(Entity, &Player), Added<Player>>,
+) {
+    for (player_entity, player) in player_query.iter() {
+        if player.id == REMOTE_PLAYER {
+            commands
+                .entity(player_entity)
+                .insert(RemoteInput::default());
+        }
+    }
+}
+
+pub fn assign_net_ids(
+    mut commands: Commands,
+    entity_query: Query<Entity, (Replicable, Without<NetId>)>,
+    mut host_session: ResMut<HostSession>,
+) {
+    for entity in entity_query.iter() {
+        commands
+            .entity(entity)
+            .insert(NetId(host_session.next_net_id));
+        host_session.next_net_id += 1;
+    }
+}
+
+pub fn receive_client_messages(
+    mut commands: Commands,
+    mut host_session: ResMut<HostSession>,
+    mut remote_input_query: Query<&mut RemoteInput>,
+    time: Res<Time>,
+) {
+    if let Some(client) = &mut host_session.client {
+        client.since_heard += time.raw_delta_seconds();
+        if client.since_heard > TIMEOUT {
+            commands.add(PrintLocalized(
+                LocalizedString::new("console.client_timed_out")
+                    .with_arg("address", client.address),
+            ));
+            host_session.client = None;
+        }
+    }
+
+    let messages = host_session.socket.receive();
+    for (address, message) in messages {
+        let from_client = host_session
+            .client
+            .as_ref()
+            .is_some_and(|client| client.address == address);
+
+        match message {
+            Message::Connect => {
+                if host_session.client.is_some() && !from_client {
+                    // Only one seat to fill.
+                    host_session.socket.send(&Message::Disconnect, address);
+                    continue;
+                }
+                if !from_client {
+                    commands.add(PrintLocalized(
+                        LocalizedString::new("console.client_joined").with_arg("address", address),
+                    ));
+                    host_session.client = Some(RemoteClient {
+                        address,
+                        player: REMOTE_PLAYER,
+                        acked_tick: None,
+                        last_input: 0,
+                        since_heard: 0.0,
+                    });
+                }
+                // Resent Connects mean our Accept got lost, so answer every one.
+                let player = host_session
+                    .client
+                    .as_ref()
+                    .map_or(REMOTE_PLAYER, |client| client.player);
+                host_session.socket.send(
+                    &Message::Accept {
+                        player: player as u8,
+                    },
+                    address,
+                );
+            }
+            Message::Input { ack, inputs } if from_client => {
+                let Some(client) = &mut host_session.client else {
+                    continue;
+                };
+                client.since_heard = 0.0;
+                if ack > client.acked_tick {
+                    client.acked_tick = ack;
+                }
+                for input in inputs {
+                    if input.sequence <= client.last_input {
+                        continue;
+                    }
+                    client.last_input = input.sequence;
+                    for mut remote_input in remote_input_query.iter_mut() {
+                        // Inputs pile up while the host is paused; keep only the latest.
+                        if remote_input.pending.len() >= MAX_PENDING_INPUTS {
+                            remote_input.pending.pop_front();
+                        }
+                        remote_input.pending.push_back(input);
+                    }
+                }
+            }
+            Message::Disconnect if from_client => {
+                commands.add(PrintLocalized(
+                    LocalizedString::new("console.client_left").with_arg("address", address),
+                ));
+                host_session.client = None;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Moves the client's player the way `Direct` steering would have on their machine.
+pub fn apply_remote_inputs(
+    mut commands: Commands,
+    mut player_query: Query<(Entity, &mut Transform, &mut Dash, &mut RemoteInput)>,
+) {
+    for (player_entity, mut transform, mut dash, mut remote_input) in player_query.iter_mut() {
+        while let Some(input) = remote_input.pending.pop_front() {
+            let direction = input.direction.normalize_or_zero();
+            if direction != Vec2::ZERO {
+                dash.direction = direction;
+            }
+
+            if input.dash && dash.ready() {
+                dash.start();
+                let dash_duration = dash.duration.duration().as_secs_f32();
+                commands
+                    .entity(player_entity)
+                    .insert(Invulnerable::new(dash_duration));
+            }
+
+            // The dash takes over steering until it ends.
+            if !dash.dashing() {
+                let delta = input.delta.clamp(0.0, MAX_INPUT_DELTA);
+                transform.translation += direction.extend(0.0) * PLAYER_SPEED * delta;
+            }
+        }
+    }
+}
+
+pub fn send_snapshots(
+    mut host_session: ResMut<HostSession>,
+    entity_query: Query<ReplicatedState, Replicable>,
+    score: Res<Score>,
+    time: Res<Time>,
+) {
+    host_session.snapshot_timer.tick(time.raw_delta());
+    if !host_session.snapshot_timer.just_finished() {
+        return;
+    }
+    let Some((address, acked_tick, last_input)) = host_session
+        .client
+        .as_ref()
+        .map(|client| (client.address, client.acked_tick, client.last_input))
+    else {
+        return;
+    };
+
+    host_session.tick += 1;
+    let mut snapshot = Snapshot {
+        tick: host_session.tick,
+        ..default()
+    };
+    for (net_id, transform, player, enemy) in entity_query.iter() {
+        let kind = match (player, enemy) {
+            (Some(player), _) => EntityKind::Player(player.id as u8),
+            (_, Some(enemy)) => EntityKind::Enemy {
+                archetype: ARCHETYPES
+                    .iter()
+                    .position(|archetype| archetype.kind == enemy.kind)
+                    .unwrap_or_default() as u8,
+                size: enemy.size,
+            },
+            (None, None) => EntityKind::Star,
+        };
+        snapshot.entities.insert(
+            net_id.0,
+            EntityState {
+                kind,
+                position: transform.translation.truncate(),
+            },
+        );
+    }
+
+    // Fall back to a full snapshot if the acknowledged one is too old to still be around.
+    let baseline = acked_tick.and_then(|acked_tick| {
+        host_session
+            .history
+            .iter()
+            .find(|snapshot| snapshot.tick == acked_tick)
+    });
+    let delta = snapshot.delta(baseline, last_input, score.value);
+    host_session.socket.send(&Message::Snapshot(delta), address);
+
+    host_session.history.push_back(snapshot);
+    if host_session.history.len() > SNAPSHOT_HISTORY {
+        host_session.history.pop_front();
+    }
+}
+
+/// Ends the client's game too; they have to join again for the next run.
+pub fn send_game_over(
+    mut game_over_event_reader: EventReader<GameOver>,
+    mut host_session: ResMut<HostSession>,
+) {
+    for event in game_over_event_reader.iter() {
+        if let Some(client) = host_session.client.take() {
+            host_session.socket.send(
+                &Message::GameOver {
+                    score: event.score,
+                    winner: event.winner.map(|winner| winner as u8),
+                },
+                client.address,
+            );
+        }
+    }
+}
+
+pub fn close_host_session(mut commands: Commands, host_session: Res<HostSession>) {
+    if let Some(client) = &host_session.client {
+        host_session
+            .socket
+            .send(&Message::Disconnect, client.address);
+    }
+    commands.remove_resource::<HostSession>();
+}
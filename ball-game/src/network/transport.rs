@@ -0,0 +1,169 @@
+use std::{
+    io::{self, ErrorKind},
+    net::{SocketAddr, UdpSocket},
+};
+
+use bevy::prelude::*;
+
+use super::protocol::Message;
+
+/// Largest payload a UDP datagram can carry.
+const MAX_DATAGRAM_SIZE: usize = 65_507;
+
+/// Non-blocking UDP socket that speaks in `Message`s.
+pub struct NetSocket {
+    socket: UdpSocket,
+    /// Reused by every `receive`, so polling each frame doesn't allocate a datagram's worth.
+    buffer: Vec<u8>,
+}
+
+impl NetSocket {
+    pub fn bind(address: SocketAddr) -> io::Result<Self> {
+        let socket = UdpSocket::bind(address)?;
+        socket.set_nonblocking(true)?;
+        Ok(NetSocket {
+            socket,
+            buffer: vec![0; MAX_DATAGRAM_SIZE],
+        })
+    }
+
+    /// Fire and forget; UDP gives no delivery guarantee anyway.
+    pub fn send(&self, message: &Message, address: SocketAddr) {
+        if let Err(error) = self.socket.send_to(&message.encode(), address) {
+            warn!("Failed to send to {address}: {error}");
+        }
+    }
+
+    /// Every message that has arrived since the last call.
+    pub fn receive(&mut self) -> Vec<(SocketAddr, Message)> {
+        let mut messages = Vec::new();
+
+        loop {
+            match self.socket.recv_from(&mut self.buffer) {
+                Ok((length, address)) => match Message::decode(&self.buffer[..length]) {
+                    Some(message) => messages.push((address, message)),
+                    None => warn!("Ignoring malformed datagram from {address}"),
+                },
+                Err(error) if error.kind() == ErrorKind::WouldBlock => break,
+                // Some platforms report an earlier send to a closed port here; skip it.
+                Err(error) if error.kind() == ErrorKind::ConnectionReset => continue,
+                Err(error) => {
+                    warn!("Failed to receive: {error}");
+                    break;
+                }
+            }
+        }
+
+        messages
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        net::{Ipv4Addr, SocketAddr},
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use bevy::prelude::*;
+
+    use super::NetSocket;
+    use crate::network::protocol::{EntityKind, EntityState, Message, PlayerInput, Snapshot};
+
+    fn bind_localhost() -> NetSocket {
+        NetSocket::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, 0))).unwrap()
+    }
+
+    /// Polls until a message arrives, as the game does once per frame.
+    fn receive_one(socket: &mut NetSocket) -> (SocketAddr, Message) {
+        let deadline = Instant::now() + Duration::from_secs(2);
+        loop {
+            if let Some(received) = socket.receive().into_iter().next() {
+                return received;
+            }
+            assert!(Instant::now() < deadline, "no message arrived");
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn host_and_client_play_over_localhost() {
+        let mut host = bind_localhost();
+        let mut client = bind_localhost();
+        let host_address = host.socket.local_addr().unwrap();
+
+        // Handshake.
+        client.send(&Message::Connect, host_address);
+        let (client_address, message) = receive_one(&mut host);
+        assert_eq!(message, Message::Connect);
+        host.send(&Message::Accept { player: 1 }, client_address);
+        assert_eq!(receive_one(&mut client).1, Message::Accept { player: 1 });
+
+        // The client's input reaches the host.
+        let input = PlayerInput {
+            sequence: 1,
+            direction: Vec2::X,
+            dash: false,
+            delta: 1.0 / 60.0,
+        };
+        client.send(
+            &Message::Input {
+                ack: None,
+                inputs: vec![input],
+            },
+            host_address,
+        );
+        assert_eq!(
+            receive_one(&mut host).1,
+            Message::Input {
+                ack: None,
+                inputs: vec![input],
+            }
+        );
+
+        // A full snapshot, then a delta against it, rebuild the host's state on the client.
+        let mut first = Snapshot {
+            tick: 1,
+            ..default()
+        };
+        first.entities.insert(
+            1,
+            EntityState {
+                kind: EntityKind::Player(1),
+                position: Vec2::new(100.0, 100.0),
+            },
+        );
+        first.entities.insert(
+            2,
+            EntityState {
+                kind: EntityKind::Star,
+                position: Vec2::new(40.0, 40.0),
+            },
+        );
+        let mut second = first.clone();
+        second.tick = 2;
+        second.entities.get_mut(&1).unwrap().position.x += 8.0;
+        second.entities.remove(&2);
+
+        host.send(&Message::Snapshot(first.delta(None, 1, 0)), client_address);
+        let Message::Snapshot(delta) = receive_one(&mut client).1 else {
+            panic!("expected a snapshot");
+        };
+        let received_first = Snapshot::apply(None, &delta);
+        assert_eq!(received_first, first);
+
+        host.send(
+            &Message::Snapshot(second.delta(Some(&first), 1, 1)),
+            client_address,
+        );
+        let Message::Snapshot(delta) = receive_one(&mut client).1 else {
+            panic!("expected a snapshot");
+        };
+        assert_eq!(delta.baseline, Some(1));
+        assert_eq!(Snapshot::apply(Some(&received_first), &delta), second);
+
+        client.send(&Message::Disconnect, host_address);
+        assert_eq!(receive_one(&mut host).1, Message::Disconnect);
+    }
+}
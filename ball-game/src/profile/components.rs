@@ -0,0 +1,10 @@
+use bevy::prelude::Component;
+
+#[derive(Component)]
+pub struct ProfileMenu;
+
+#[derive(Component)]
+pub struct ProfileSelectMenu;
+
+#[derive(Component)]
+pub struct NewProfileText;
@@ -0,0 +1,113 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use super::{
+    resources::{ActiveProfile, LifetimeStats, ProfileList, SavedSettings},
+    SETTINGS_FILE,
+};
+use crate::{
+    achievements::resources::Achievements,
+    audio::resources::AudioSettings,
+    camera::resources::CameraSettings,
+    game::{campaign::resources::CampaignProgress, score::resources::HighScores},
+    localization::resources::LanguageSettings,
+    save::files::{read_ron, write_ron},
+    settings::resources::{ControlSettings, KeyBindings},
+    theme::resources::ThemeSettings,
+    AppState,
+};
+
+/// Every resource a profile owns.
+#[derive(SystemParam)]
+pub struct ProfileData<'w> {
+    control_settings: ResMut<'w, ControlSettings>,
+    key_bindings: ResMut<'w, KeyBindings>,
+    camera_settings: ResMut<'w, CameraSettings>,
+    audio_settings: ResMut<'w, AudioSettings>,
+    theme_settings: ResMut<'w, ThemeSettings>,
+    language_settings: ResMut<'w, LanguageSettings>,
+    high_scores: ResMut<'w, HighScores>,
+    achievements: ResMut<'w, Achievements>,
+    campaign_progress: ResMut<'w, CampaignProgress>,
+    lifetime_stats: ResMut<'w, LifetimeStats>,
+}
+
+impl<'w> ProfileData<'w> {
+    /// Anything the profile hasn't saved yet starts from the defaults.
+    pub fn load(&mut self, profile: &ActiveProfile) {
+        let settings: SavedSettings = read_ron(&profile.path(SETTINGS_FILE)).unwrap_or_default();
+        *self.control_settings = settings.controls;
+        *self.key_bindings = settings.key_bindings;
+        *self.camera_settings = settings.camera;
+        *self.audio_settings = settings.audio;
+        *self.theme_settings = settings.theme;
+        *self.language_settings = settings.language;
+
+        *self.high_scores = HighScores::load(profile);
+        *self.achievements = Achievements::load(profile);
+        *self.campaign_progress = CampaignProgress::load(profile);
+        *self.lifetime_stats = LifetimeStats::load(profile);
+    }
+
+    /// Lifetime stats change every frame in game, so they are saved at the end of runs instead.
+    pub fn save_changed(&self, profile: &ActiveProfile) {
+        if self.control_settings.is_changed()
+            || self.key_bindings.is_changed()
+            || self.camera_settings.is_changed()
+            || self.audio_settings.is_changed()
+            || self.theme_settings.is_changed()
+            || self.language_settings.is_changed()
+        {
+            let settings = SavedSettings {
+                controls: self.control_settings.clone(),
+                key_bindings: self.key_bindings.clone(),
+                camera: self.camera_settings.clone(),
+                audio: self.audio_settings.clone(),
+                theme: self.theme_settings.clone(),
+                language: self.language_settings.clone(),
+            };
+            write_ron(&profile.path(SETTINGS_FILE), &settings);
+        }
+        if self.high_scores.is_changed() {
+            self.high_scores.save(profile);
+        }
+        if self.achievements.is_changed() {
+            self.achievements.save(profile);
+        }
+        if self.campaign_progress.is_changed() {
+            self.campaign_progress.save(profile);
+        }
+    }
+}
+
+/// Makes a profile the active one and heads to the main menu.
+#[derive(SystemParam)]
+pub struct ProfileSwitcher<'w, 's> {
+    commands: Commands<'w, 's>,
+    profile_data: ProfileData<'w>,
+    profile_list: ResMut<'w, ProfileList>,
+    next_app_state: ResMut<'w, NextState<AppState>>,
+}
+
+impl<'w, 's> ProfileSwitcher<'w, 's> {
+    /// Creates the profile if there's none by that name yet.
+    pub fn switch(&mut self, name: &str) {
+        let profile = ActiveProfile {
+            name: name.to_string(),
+        };
+        self.profile_data.load(&profile);
+
+        if !self
+            .profile_list
+            .names
+            .iter()
+            .any(|existing| existing == name)
+        {
+            self.profile_list.names.push(name.to_string());
+        }
+        self.profile_list.last = Some(name.to_string());
+        self.profile_list.save();
+
+        self.commands.insert_resource(profile);
+        self.next_app_state.set(AppState::MainMenu);
+    }
+}
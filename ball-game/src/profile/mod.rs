@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+use systems::interactions::*;
+use systems::layout::*;
+use systems::tracking::*;
+
+use crate::{
+    game::{level::resources::PlaytestLevel, SimulationState},
+    AppState,
+};
+
+use self::resources::{ActiveProfile, LifetimeStats, ProfileList};
+
+mod components;
+pub mod data;
+pub mod resources;
+mod systems;
+
+const PROFILE_LIST_PATH: &str = "saves/profiles.ron";
+/// Each profile gets a directory in here named after it.
+const PROFILES_DIR: &str = "saves/profiles";
+const SETTINGS_FILE: &str = "settings.ron";
+const STATS_FILE: &str = "stats.ron";
+const MAX_PROFILE_NAME_LENGTH: usize = 16;
+const PROFILE_BUTTON_WIDTH: f32 = 280.0;
+
+pub struct ProfilePlugin;
+
+impl Plugin for ProfilePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(ProfileList::load())
+            .init_resource::<LifetimeStats>()
+            .add_systems(
+                Last,
+                save_profile_data.run_if(resource_exists::<ActiveProfile>()),
+            )
+            // Test plays from the editor don't count.
+            .add_systems(
+                Update,
+                (
+                    track_playtime.run_if(in_state(SimulationState::Running)),
+                    track_stars,
+                    track_deaths,
+                )
+                    .run_if(in_state(AppState::InGame))
+                    .run_if(not(resource_exists::<PlaytestLevel>())),
+            )
+            // The game is already over by the time this sees the event.
+            .add_systems(
+                Update,
+                track_finished_games
+                    .run_if(resource_exists::<ActiveProfile>())
+                    .run_if(not(resource_exists::<PlaytestLevel>())),
+            )
+            .add_systems(
+                OnExit(AppState::InGame),
+                save_lifetime_stats
+                    .run_if(resource_exists::<ActiveProfile>())
+                    .run_if(not(resource_exists::<PlaytestLevel>())),
+            )
+            .add_systems(OnEnter(AppState::ProfileSelect), spawn_profile_select_menu)
+            .add_systems(
+                Update,
+                (
+                    type_profile_name,
+                    update_new_profile_text,
+                    handle_profile_select_actions,
+                )
+                    .run_if(in_state(AppState::ProfileSelect)),
+            )
+            .add_systems(OnExit(AppState::ProfileSelect), despawn_profile_select_menu)
+            .add_systems(OnEnter(AppState::Profile), spawn_profile_menu)
+            .add_systems(OnExit(AppState::Profile), despawn_profile_menu);
+    }
+}
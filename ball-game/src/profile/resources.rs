@@ -0,0 +1,104 @@
+use bevy::{prelude::*, utils::HashMap};
+use serde::{Deserialize, Serialize};
+
+use super::{PROFILES_DIR, PROFILE_LIST_PATH, STATS_FILE};
+use crate::{
+    audio::resources::AudioSettings,
+    camera::resources::CameraSettings,
+    events::DeathCause,
+    localization::resources::LanguageSettings,
+    save::files::{read_ron, write_ron},
+    settings::resources::{ControlSettings, KeyBindings},
+    theme::resources::ThemeSettings,
+};
+
+/// Whose settings and progress are in use. Inserted once someone picks a profile.
+#[derive(Resource, Debug, Clone)]
+pub struct ActiveProfile {
+    pub name: String,
+}
+
+impl ActiveProfile {
+    /// Where the profile keeps `file`.
+    pub fn path(&self, file: &str) -> String {
+        format!("{PROFILES_DIR}/{}/{file}", self.name)
+    }
+}
+
+/// Every profile created on this machine.
+#[derive(Resource, Default, Serialize, Deserialize)]
+pub struct ProfileList {
+    pub names: Vec<String>,
+    /// The profile picked most recently, listed first.
+    pub last: Option<String>,
+}
+
+impl ProfileList {
+    pub fn load() -> Self {
+        read_ron(PROFILE_LIST_PATH).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        write_ron(PROFILE_LIST_PATH, self);
+    }
+
+    /// Most recently used first, then in creation order.
+    pub fn ordered(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.names.iter().map(String::as_str).collect();
+        if let Some(last) = &self.last {
+            if let Some(index) = names.iter().position(|name| name == last) {
+                let name = names.remove(index);
+                names.insert(0, name);
+            }
+        }
+        names
+    }
+}
+
+/// Name being typed for a new profile on the profile select screen.
+#[derive(Resource, Default)]
+pub struct NewProfileName {
+    pub name: String,
+}
+
+/// Per-profile settings, kept together in one file.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SavedSettings {
+    pub controls: ControlSettings,
+    pub key_bindings: KeyBindings,
+    pub camera: CameraSettings,
+    pub audio: AudioSettings,
+    pub theme: ThemeSettings,
+    pub language: LanguageSettings,
+}
+
+/// Totals across every run ever played, test plays from the editor aside.
+#[derive(Resource, Default, Serialize, Deserialize)]
+pub struct LifetimeStats {
+    pub games_played: u32,
+    pub stars_collected: u32,
+    /// Seconds spent playing, pauses excluded.
+    pub playtime: f64,
+    pub total_score: u64,
+    pub best_score: u32,
+    pub deaths: HashMap<DeathCause, u32>,
+}
+
+impl LifetimeStats {
+    pub fn load(profile: &ActiveProfile) -> Self {
+        read_ron(&profile.path(STATS_FILE)).unwrap_or_default()
+    }
+
+    pub fn save(&self, profile: &ActiveProfile) {
+        write_ron(&profile.path(STATS_FILE), self);
+    }
+
+    pub fn average_score(&self) -> f64 {
+        if self.games_played == 0 {
+            0.0
+        } else {
+            self.total_score as f64 / self.games_played as f64
+        }
+    }
+}
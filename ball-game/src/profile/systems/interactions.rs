@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+
+use crate::{
+    events::MenuButtonPressed,
+    profile::{data::ProfileSwitcher, resources::NewProfileName, MAX_PROFILE_NAME_LENGTH},
+    widgets::components::MenuAction,
+};
+
+/// Picking an existing name just switches to that profile.
+pub fn handle_profile_select_actions(
+    mut menu_button_event_reader: EventReader<MenuButtonPressed>,
+    mut profile_switcher: ProfileSwitcher,
+    new_profile_name: Res<NewProfileName>,
+) {
+    for event in menu_button_event_reader.iter() {
+        match &event.action {
+            MenuAction::SelectProfile(name) => profile_switcher.switch(name),
+            MenuAction::CreateProfile => {
+                let name = new_profile_name.name.trim();
+                if !name.is_empty() {
+                    profile_switcher.switch(name);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Names end up in file paths, so only a safe set of characters is accepted.
+pub fn type_profile_name(
+    mut received_character_reader: EventReader<ReceivedCharacter>,
+    keyboard_input: Res<Input<KeyCode>>,
+    mut new_profile_name: ResMut<NewProfileName>,
+) {
+    for event in received_character_reader.iter() {
+        let character = event.char;
+        let allowed = character.is_ascii_alphanumeric()
+            || (matches!(character, ' ' | '-' | '_') && !new_profile_name.name.is_empty());
+        if allowed && new_profile_name.name.len() < MAX_PROFILE_NAME_LENGTH {
+            new_profile_name.name.push(character);
+        }
+    }
+    if keyboard_input.just_pressed(KeyCode::Back) {
+        new_profile_name.name.pop();
+    }
+}
@@ -0,0 +1,230 @@
+use bevy::prelude::*;
+
+use crate::{
+    localization::{components::LocalizedText, resources::LocalizedString},
+    main_menu::styles::{get_body_text_style, get_main_menu_style, get_title_text_style},
+    profile::{
+        components::*,
+        resources::{ActiveProfile, LifetimeStats, NewProfileName, ProfileList},
+        PROFILE_BUTTON_WIDTH,
+    },
+    theme::{
+        components::{MenuBackground, ThemedText},
+        resources::UiTheme,
+    },
+    widgets::{builder::MenuButtonBuilder, components::MenuAction},
+    AppState,
+};
+
+fn format_playtime(seconds: f64) -> String {
+    let seconds = seconds as u64;
+    format!(
+        "{}h {:02}m {:02}s",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+fn stat_lines(lifetime_stats: &LifetimeStats) -> Vec<LocalizedString> {
+    let mut lines = vec![
+        LocalizedString::new("profile.games_played").with_arg("count", lifetime_stats.games_played),
+        LocalizedString::new("profile.stars_collected")
+            .with_arg("count", lifetime_stats.stars_collected),
+        LocalizedString::new("profile.playtime")
+            .with_arg("time", format_playtime(lifetime_stats.playtime)),
+        LocalizedString::new("profile.average_score")
+            .with_arg("score", format!("{:.1}", lifetime_stats.average_score())),
+        LocalizedString::new("profile.best_score").with_arg("score", lifetime_stats.best_score),
+    ];
+
+    // Deadliest first.
+    let mut deaths: Vec<_> = lifetime_stats.deaths.iter().collect();
+    deaths.sort_by(|a, b| {
+        b.1.cmp(a.1)
+            .then_with(|| a.0.label_key().cmp(b.0.label_key()))
+    });
+    if deaths.is_empty() {
+        lines.push(LocalizedString::new("profile.no_deaths"));
+    } else {
+        lines.push(LocalizedString::new("profile.deaths"));
+        lines.extend(deaths.into_iter().map(|(cause, count)| {
+            LocalizedString::new("profile.death_count")
+                .with_localized_arg("cause", LocalizedString::new(cause.label_key()))
+                .with_arg("count", count)
+        }));
+    }
+
+    lines
+}
+
+pub fn spawn_profile_menu(
+    mut commands: Commands,
+    ui_theme: Res<UiTheme>,
+    lifetime_stats: Res<LifetimeStats>,
+    active_profile: Res<ActiveProfile>,
+) {
+    build_profile_menu(&mut commands, &ui_theme, &lifetime_stats, &active_profile);
+}
+
+pub fn despawn_profile_menu(
+    mut commands: Commands,
+    profile_menu_query: Query<Entity, With<ProfileMenu>>,
+) {
+    if let Ok(profile_menu_entity) = profile_menu_query.get_single() {
+        commands.entity(profile_menu_entity).despawn_recursive();
+    }
+}
+
+pub fn build_profile_menu(
+    commands: &mut Commands,
+    ui_theme: &UiTheme,
+    lifetime_stats: &LifetimeStats,
+    active_profile: &ActiveProfile,
+) -> Entity {
+    let profile_menu_entity = commands
+        .spawn((
+            NodeBundle {
+                style: get_main_menu_style(ui_theme),
+                background_color: ui_theme.palette.background.into(),
+                ..default()
+            },
+            MenuBackground {},
+            ProfileMenu {},
+        ))
+        .with_children(|parent| {
+            // === Title ===
+            parent.spawn((
+                TextBundle::from_section(
+                    active_profile.name.as_str(),
+                    get_title_text_style(ui_theme),
+                ),
+                ThemedText::Title,
+            ));
+            // === Stats ===
+            for line in stat_lines(lifetime_stats) {
+                parent.spawn((
+                    TextBundle::from_section("", get_body_text_style(ui_theme)),
+                    LocalizedText::new(line),
+                    ThemedText::Body,
+                ));
+            }
+            // === Achievements Button ===
+            MenuButtonBuilder::new(
+                LocalizedString::new("profile.achievements"),
+                MenuAction::GoTo(AppState::Achievements),
+                0,
+            )
+            .width(PROFILE_BUTTON_WIDTH)
+            .spawn(parent, ui_theme);
+            // === Switch Profile Button ===
+            MenuButtonBuilder::new(
+                LocalizedString::new("profile.switch"),
+                MenuAction::GoTo(AppState::ProfileSelect),
+                1,
+            )
+            .width(PROFILE_BUTTON_WIDTH)
+            .spawn(parent, ui_theme);
+            // === Back Button ===
+            MenuButtonBuilder::new(
+                LocalizedString::new("common.back"),
+                MenuAction::GoTo(AppState::MainMenu),
+                2,
+            )
+            .spawn(parent, ui_theme);
+        })
+        .id();
+
+    profile_menu_entity
+}
+
+pub fn new_profile_label(new_profile_name: &NewProfileName) -> LocalizedString {
+    LocalizedString::new("profile_select.new").with_arg("name", &new_profile_name.name)
+}
+
+pub fn spawn_profile_select_menu(
+    mut commands: Commands,
+    ui_theme: Res<UiTheme>,
+    profile_list: Res<ProfileList>,
+) {
+    commands.insert_resource(NewProfileName::default());
+    build_profile_select_menu(&mut commands, &ui_theme, &profile_list);
+}
+
+pub fn despawn_profile_select_menu(
+    mut commands: Commands,
+    profile_select_menu_query: Query<Entity, With<ProfileSelectMenu>>,
+) {
+    commands.remove_resource::<NewProfileName>();
+    if let Ok(profile_select_menu_entity) = profile_select_menu_query.get_single() {
+        commands
+            .entity(profile_select_menu_entity)
+            .despawn_recursive();
+    }
+}
+
+pub fn build_profile_select_menu(
+    commands: &mut Commands,
+    ui_theme: &UiTheme,
+    profile_list: &ProfileList,
+) -> Entity {
+    let profile_count = profile_list.names.len() as u32;
+
+    let profile_select_menu_entity = commands
+        .spawn((
+            NodeBundle {
+                style: get_main_menu_style(ui_theme),
+                background_color: ui_theme.palette.background.into(),
+                ..default()
+            },
+            MenuBackground {},
+            ProfileSelectMenu {},
+        ))
+        .with_children(|parent| {
+            // === Title ===
+            parent.spawn((
+                TextBundle::from_section("", get_title_text_style(ui_theme)),
+                LocalizedText::new(LocalizedString::new("profile_select.title")),
+                ThemedText::Title,
+            ));
+            // === Profile Buttons ===
+            for (index, name) in profile_list.ordered().into_iter().enumerate() {
+                MenuButtonBuilder::new(
+                    LocalizedString::raw(name),
+                    MenuAction::SelectProfile(name.to_string()),
+                    index as u32,
+                )
+                .width(PROFILE_BUTTON_WIDTH)
+                .spawn(parent, ui_theme);
+            }
+            // === New Profile Name ===
+            parent.spawn((
+                TextBundle::from_section("", get_body_text_style(ui_theme)),
+                LocalizedText::new(new_profile_label(&NewProfileName::default())),
+                ThemedText::Body,
+                NewProfileText {},
+            ));
+            // === Create Button ===
+            MenuButtonBuilder::new(
+                LocalizedString::new("profile_select.create"),
+                MenuAction::CreateProfile,
+                profile_count,
+            )
+            .width(PROFILE_BUTTON_WIDTH)
+            .spawn(parent, ui_theme);
+        })
+        .id();
+
+    profile_select_menu_entity
+}
+
+pub fn update_new_profile_text(
+    mut text_query: Query<&mut LocalizedText, With<NewProfileText>>,
+    new_profile_name: Res<NewProfileName>,
+) {
+    if new_profile_name.is_changed() {
+        if let Ok(mut text) = text_query.get_single_mut() {
+            text.sections[0] = new_profile_label(&new_profile_name);
+        }
+    }
+}
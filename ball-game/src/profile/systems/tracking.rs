@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+use crate::{
+    events::{GameOver, PlayerDied, StarCollected},
+    profile::{
+        data::ProfileData,
+        resources::{ActiveProfile, LifetimeStats},
+    },
+};
+
+pub fn track_playtime(mut lifetime_stats: ResMut<LifetimeStats>, time: Res<Time>) {
+    lifetime_stats.playtime += time.delta_seconds_f64();
+}
+
+pub fn track_stars(
+    mut star_collected_event_reader: EventReader<StarCollected>,
+    mut lifetime_stats: ResMut<LifetimeStats>,
+) {
+    for _ in star_collected_event_reader.iter() {
+        lifetime_stats.stars_collected += 1;
+    }
+}
+
+pub fn track_deaths(
+    mut player_died_event_reader: EventReader<PlayerDied>,
+    mut lifetime_stats: ResMut<LifetimeStats>,
+) {
+    for event in player_died_event_reader.iter() {
+        *lifetime_stats.deaths.entry(event.cause).or_default() += 1;
+    }
+}
+
+pub fn track_finished_games(
+    mut game_over_event_reader: EventReader<GameOver>,
+    mut lifetime_stats: ResMut<LifetimeStats>,
+    active_profile: Res<ActiveProfile>,
+) {
+    for event in game_over_event_reader.iter() {
+        lifetime_stats.games_played += 1;
+        lifetime_stats.total_score += u64::from(event.score);
+        lifetime_stats.best_score = lifetime_stats.best_score.max(event.score);
+        lifetime_stats.save(&active_profile);
+    }
+}
+
+/// Keeps the playtime and stars of runs that were left without finishing.
+pub fn save_lifetime_stats(lifetime_stats: Res<LifetimeStats>, active_profile: Res<ActiveProfile>) {
+    lifetime_stats.save(&active_profile);
+}
+
+/// Runs in `Last`, so a profile switch earlier in the frame has already taken effect and the
+/// newly loaded data isn't written over the previous profile's files.
+pub fn save_profile_data(profile_data: ProfileData, active_profile: Res<ActiveProfile>) {
+    profile_data.save_changed(&active_profile);
+}
@@ -0,0 +1,41 @@
+use std::{fs, path::Path};
+
+use bevy::prelude::*;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Reads a RON file; `None` when it doesn't exist or doesn't parse, the latter being logged.
+pub fn read_ron<T: DeserializeOwned>(path: &str) -> Option<T> {
+    let contents = fs::read_to_string(path).ok()?;
+    match ron::from_str(&contents) {
+        Ok(value) => Some(value),
+        Err(error) => {
+            error!("Failed to parse {path}: {error}");
+            None
+        }
+    }
+}
+
+/// Writes `value` as pretty RON, creating missing directories. Failures are logged and reported
+/// with `false`.
+pub fn write_ron<T: Serialize>(path: &str, value: &T) -> bool {
+    let contents = match ron::ser::to_string_pretty(value, default()) {
+        Ok(contents) => contents,
+        Err(error) => {
+            error!("Failed to serialize {path}: {error}");
+            return false;
+        }
+    };
+    if let Some(directory) = Path::new(path).parent() {
+        if let Err(error) = fs::create_dir_all(directory) {
+            error!("Failed to create {}: {error}", directory.display());
+            return false;
+        }
+    }
+    match fs::write(path, contents) {
+        Ok(()) => true,
+        Err(error) => {
+            error!("Failed to write {path}: {error}");
+            false
+        }
+    }
+}
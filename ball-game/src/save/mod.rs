@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+use crate::AppState;
+
+use self::{resources::ResumeRun, systems::*};
+
+pub mod files;
+pub mod resources;
+mod systems;
+
+/// Kept in the active profile's directory, so each profile has its own run to continue.
+const SAVE_FILE: &str = "run.ron";
+
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            continue_saved_run.run_if(in_state(AppState::MainMenu)),
+        )
+        .add_systems(
+            OnEnter(AppState::InGame),
+            restore_run.run_if(resource_exists::<ResumeRun>()),
+        )
+        .add_systems(OnExit(AppState::InGame), save_run.run_if(in_free_play))
+        // Runs after `save_run`, since leaving `InGame` comes first.
+        .add_systems(
+            OnEnter(AppState::GameOver),
+            delete_save.run_if(in_free_play),
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use std::fs;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game::{
+        boss::{
+            components::{Boss, BossBullet},
+            resources::BossSchedule,
+        },
+        enemy::components::{Dasher, Enemy, Homing, SineWave, Splitter},
+        mode::resources::GameMode,
+        player::{
+            components::{Acceleration, Dash, Invulnerable, Player, Velocity},
+            resources::{Lives, PlayerMode, Respawns},
+        },
+        resources::GameRng,
+        score::resources::Score,
+    },
+    profile::resources::ActiveProfile,
+};
+
+use super::{files::read_ron, SAVE_FILE};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SavedPlayer {
+    pub position: Vec2,
+    pub player: Player,
+    pub dash: Dash,
+    pub velocity: Velocity,
+    pub acceleration: Acceleration,
+    pub invulnerable: Option<Invulnerable>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SavedEnemy {
+    pub position: Vec2,
+    pub enemy: Enemy,
+    pub homing: Option<Homing>,
+    pub sine_wave: Option<SineWave>,
+    pub splitter: Option<Splitter>,
+    pub dasher: Option<Dasher>,
+}
+
+/// Everything needed to pick a run back up exactly where it was left.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SaveGame {
+    pub player_mode: PlayerMode,
+    /// Defaulted so saves from before game modes still load.
+    #[serde(default)]
+    pub game_mode: GameMode,
+    /// Obstacles aren't saved; they come back from the level.
+    pub level: Option<usize>,
+    pub rng: GameRng,
+    pub score: Score,
+    pub lives: Lives,
+    pub respawns: Respawns,
+    pub enemy_spawn_timer: Timer,
+    pub star_spawn_timer: Timer,
+    pub boss_schedule: BossSchedule,
+    /// Remaining time in time attack.
+    #[serde(default)]
+    pub time_limit: Option<Timer>,
+    pub players: Vec<SavedPlayer>,
+    pub enemies: Vec<SavedEnemy>,
+    pub stars: Vec<Vec2>,
+    pub boss: Option<(Vec2, Boss)>,
+    pub bullets: Vec<(Vec2, BossBullet)>,
+}
+
+impl SaveGame {
+    pub fn exists(active_profile: &ActiveProfile) -> bool {
+        fs::metadata(active_profile.path(SAVE_FILE)).is_ok()
+    }
+
+    pub fn load(active_profile: &ActiveProfile) -> Option<SaveGame> {
+        read_ron(&active_profile.path(SAVE_FILE))
+    }
+}
+
+/// Present when entering `AppState::InGame` to continue a saved run instead of starting fresh.
+#[derive(Resource)]
+pub struct ResumeRun(pub SaveGame);
@@ -0,0 +1,419 @@
+use std::fs;
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use super::{
+    files::write_ron,
+    resources::{ResumeRun, SaveGame, SavedEnemy, SavedPlayer},
+    SAVE_FILE,
+};
+use crate::{
+    events::MenuButtonPressed,
+    game::{
+        boss::{
+            components::{Boss, BossBullet},
+            resources::{BossEncounter, BossSchedule},
+            spawn_boss, spawn_bullet,
+        },
+        campaign::resources::CampaignRun,
+        enemy::{
+            archetypes::archetype,
+            components::{Dasher, Enemy, Homing, SineWave, Splitter},
+            enemy_sprite_bundle,
+            resources::EnemySpawnTimer,
+        },
+        level::resources::{PlaytestLevel, SelectedLevel},
+        mode::resources::{GameMode, TimeLimit},
+        player::{
+            components::{Acceleration, Dash, Invulnerable, Player, Velocity},
+            resources::{Lives, PlayerMode, Respawns},
+            spawn_player,
+        },
+        resources::GameRng,
+        score::resources::Score,
+        star::{components::Star, resources::StarSpawnTimer, spawn_star},
+    },
+    loading::resources::GameAssets,
+    localization::{console::PrintLocalized, resources::LocalizedString},
+    profile::resources::ActiveProfile,
+    widgets::components::MenuAction,
+    AppState,
+};
+
+type PlayerState<'a> = (
+    &'a Transform,
+    &'a Player,
+    &'a Dash,
+    &'a Velocity,
+    &'a Acceleration,
+    Option<&'a Invulnerable>,
+);
+
+type EnemyState<'a> = (
+    &'a Transform,
+    &'a Enemy,
+    Option<&'a Homing>,
+    Option<&'a SineWave>,
+    Option<&'a Splitter>,
+    Option<&'a Dasher>,
+);
+
+#[derive(SystemParam)]
+pub struct RunResources<'w> {
+    player_mode: Res<'w, PlayerMode>,
+    game_mode: Res<'w, GameMode>,
+    selected_level: Res<'w, SelectedLevel>,
+    rng: Res<'w, GameRng>,
+    score: Res<'w, Score>,
+    lives: Res<'w, Lives>,
+    respawns: Res<'w, Respawns>,
+    enemy_spawn_timer: Res<'w, EnemySpawnTimer>,
+    star_spawn_timer: Res<'w, StarSpawnTimer>,
+    boss_schedule: Res<'w, BossSchedule>,
+    time_limit: Option<Res<'w, TimeLimit>>,
+    active_profile: Res<'w, ActiveProfile>,
+}
+
+/// Test plays from the editor and campaign stages aren't saved.
+pub fn in_free_play(
+    playtest_level: Option<Res<PlaytestLevel>>,
+    campaign_run: Option<Res<CampaignRun>>,
+) -> bool {
+    playtest_level.is_none() && campaign_run.is_none()
+}
+
+/// Writes the run to disk on the way out; removing resources and despawning is deferred, so
+/// everything is still around here.
+pub fn save_run(
+    mut commands: Commands,
+    run_resources: RunResources,
+    player_query: Query<PlayerState>,
+    enemy_query: Query<EnemyState>,
+    star_query: Query<&Transform, With<Star>>,
+    boss_query: Query<(&Transform, &Boss)>,
+    bullet_query: Query<(&Transform, &BossBullet)>,
+) {
+    let save_game = SaveGame {
+        player_mode: *run_resources.player_mode,
+        game_mode: *run_resources.game_mode,
+        level: run_resources.selected_level.level,
+        rng: run_resources.rng.clone(),
+        score: run_resources.score.clone(),
+        lives: run_resources.lives.clone(),
+        respawns: run_resources.respawns.clone(),
+        enemy_spawn_timer: run_resources.enemy_spawn_timer.timer.clone(),
+        star_spawn_timer: run_resources.star_spawn_timer.timer.clone(),
+        boss_schedule: run_resources.boss_schedule.clone(),
+        time_limit: run_resources
+            .time_limit
+            .as_ref()
+            .map(|time_limit| time_limit.timer.clone()),
+        players: player_query
+            .iter()
+            .map(
+                |(transform, player, dash, velocity, acceleration, invulnerable)| SavedPlayer {
+                    position: transform.translation.truncate(),
+                    player: player.clone(),
+                    dash: dash.clone(),
+                    velocity: velocity.clone(),
+                    acceleration: acceleration.clone(),
+                    invulnerable: invulnerable.cloned(),
+                },
+            )
+            .collect(),
+        enemies: enemy_query
+            .iter()
+            .map(
+                |(transform, enemy, homing, sine_wave, splitter, dasher)| SavedEnemy {
+                    position: transform.translation.truncate(),
+                    enemy: enemy.clone(),
+                    homing: homing.cloned(),
+                    sine_wave: sine_wave.cloned(),
+                    splitter: splitter.cloned(),
+                    dasher: dasher.cloned(),
+                },
+            )
+            .collect(),
+        stars: star_query
+            .iter()
+            .map(|transform| transform.translation.truncate())
+            .collect(),
+        boss: boss_query
+            .iter()
+            .next()
+            .map(|(transform, boss)| (transform.translation.truncate(), boss.clone())),
+        bullets: bullet_query
+            .iter()
+            .map(|(transform, bullet)| (transform.translation.truncate(), bullet.clone()))
+            .collect(),
+    };
+
+    let save_path = run_resources.active_profile.path(SAVE_FILE);
+    if write_ron(&save_path, &save_game) {
+        commands.add(PrintLocalized(
+            LocalizedString::new("console.saved_run").with_arg("path", save_path),
+        ));
+    }
+}
+
+/// A finished run can't be continued.
+pub fn delete_save(active_profile: Res<ActiveProfile>) {
+    if SaveGame::exists(&active_profile) {
+        let save_path = active_profile.path(SAVE_FILE);
+        if let Err(error) = fs::remove_file(&save_path) {
+            error!("Failed to delete {save_path}: {error}");
+        }
+    }
+}
+
+pub fn continue_saved_run(
+    mut commands: Commands,
+    mut menu_button_event_reader: EventReader<MenuButtonPressed>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut player_mode: ResMut<PlayerMode>,
+    mut game_mode: ResMut<GameMode>,
+    mut selected_level: ResMut<SelectedLevel>,
+    active_profile: Res<ActiveProfile>,
+) {
+    for event in menu_button_event_reader.iter() {
+        if event.action != MenuAction::Continue {
+            continue;
+        }
+        if let Some(save_game) = SaveGame::load(&active_profile) {
+            *player_mode = save_game.player_mode;
+            *game_mode = save_game.game_mode;
+            selected_level.level = save_game.level;
+            commands.insert_resource(ResumeRun(save_game));
+            next_app_state.set(AppState::InGame);
+        }
+    }
+}
+
+pub fn restore_run(
+    mut commands: Commands,
+    resume_run: Res<ResumeRun>,
+    game_assets: Res<GameAssets>,
+) {
+    let save_game = resume_run.0.clone();
+
+    commands.insert_resource(save_game.player_mode);
+    commands.insert_resource(save_game.game_mode);
+    commands.insert_resource(save_game.rng);
+    commands.insert_resource(save_game.score);
+    commands.insert_resource(save_game.lives);
+    commands.insert_resource(save_game.respawns);
+    commands.insert_resource(EnemySpawnTimer {
+        timer: save_game.enemy_spawn_timer,
+    });
+    commands.insert_resource(StarSpawnTimer {
+        timer: save_game.star_spawn_timer,
+    });
+    commands.insert_resource(save_game.boss_schedule);
+    if let Some(timer) = save_game.time_limit {
+        commands.insert_resource(TimeLimit { timer });
+    }
+    commands.insert_resource(BossEncounter {
+        active: save_game.boss.is_some(),
+    });
+
+    for saved_player in save_game.players {
+        let player_entity = spawn_player(
+            &mut commands,
+            &game_assets,
+            saved_player.player.id,
+            saved_player.position,
+        );
+        let mut player_commands = commands.entity(player_entity);
+        player_commands.insert((
+            saved_player.dash,
+            saved_player.velocity,
+            saved_player.acceleration,
+        ));
+        if let Some(invulnerable) = saved_player.invulnerable {
+            player_commands.insert(invulnerable);
+        }
+    }
+
+    for saved_enemy in save_game.enemies {
+        let archetype = archetype(saved_enemy.enemy.kind);
+        let mut enemy_commands = commands.spawn((
+            enemy_sprite_bundle(
+                &game_assets,
+                archetype,
+                saved_enemy.position,
+                saved_enemy.enemy.size,
+            ),
+            saved_enemy.enemy,
+        ));
+        if let Some(homing) = saved_enemy.homing {
+            enemy_commands.insert(homing);
+        }
+        if let Some(sine_wave) = saved_enemy.sine_wave {
+            enemy_commands.insert(sine_wave);
+        }
+        if let Some(splitter) = saved_enemy.splitter {
+            enemy_commands.insert(splitter);
+        }
+        if let Some(dasher) = saved_enemy.dasher {
+            enemy_commands.insert(dasher);
+        }
+    }
+
+    for position in save_game.stars {
+        spawn_star(&mut commands, &game_assets, position);
+    }
+
+    if let Some((position, boss)) = save_game.boss {
+        spawn_boss(&mut commands, &game_assets, position, boss);
+    }
+    for (position, bullet) in save_game.bullets {
+        spawn_bullet(&mut commands, &game_assets, position, bullet);
+    }
+
+    commands.remove_resource::<ResumeRun>();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, time::Duration};
+
+    use bevy::{
+        asset::AssetPlugin, input::InputPlugin, time::TimeUpdateStrategy, window::PrimaryWindow,
+    };
+    use rand::{RngCore, SeedableRng};
+
+    use super::*;
+    use crate::{
+        events::{CameraEvent, SoundEvent},
+        game::{level::asset::Level, GamePlugin, SimulationState},
+        save::SavePlugin,
+        settings::SettingsPlugin,
+    };
+
+    const SEED: u64 = 42;
+    const TICK: Duration = Duration::from_millis(16);
+    const TICKS_BEFORE_SAVE: usize = 400;
+    const TICKS_AFTER_SAVE: usize = 400;
+
+    /// The game with its own simulation running off a fixed clock, so two apps fed the same
+    /// ticks step identically.
+    fn headless_app(active_profile: &ActiveProfile) -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default(), InputPlugin))
+            .add_state::<AppState>()
+            .add_asset::<Level>()
+            .add_event::<SoundEvent>()
+            .add_event::<CameraEvent>()
+            .add_plugins((SettingsPlugin, GamePlugin, SavePlugin))
+            .insert_resource(ActiveProfile {
+                name: active_profile.name.clone(),
+            })
+            .init_resource::<GameAssets>();
+        app.world.spawn((Window::default(), PrimaryWindow));
+        app
+    }
+
+    fn run(app: &mut App, ticks: usize, tick: Duration) {
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(tick));
+        for _ in 0..ticks {
+            app.update();
+        }
+    }
+
+    /// Enters the game without moving anything, then lets the simulation run.
+    fn start_game(app: &mut App) {
+        app.world
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::InGame);
+        run(app, 1, Duration::ZERO);
+        app.world
+            .resource_mut::<NextState<SimulationState>>()
+            .set(SimulationState::Running);
+        run(app, 1, Duration::ZERO);
+    }
+
+    /// Seeds the run and clears the layout rolled on the way in, so everything from here on
+    /// comes from the seed.
+    fn seed_run(app: &mut App) {
+        app.insert_resource(GameRng::seed_from_u64(SEED));
+        let rolled: Vec<Entity> = app
+            .world
+            .query_filtered::<Entity, Or<(With<Enemy>, With<Star>)>>()
+            .iter(&app.world)
+            .collect();
+        for entity in rolled {
+            app.world.despawn(entity);
+        }
+    }
+
+    /// Steers the first player across the arena, so stars get picked up and the score moves.
+    fn hold_keys(app: &mut App, keys: [KeyCode; 2]) {
+        let mut keyboard_input = app.world.resource_mut::<Input<KeyCode>>();
+        keyboard_input.release_all();
+        for key in keys {
+            keyboard_input.press(key);
+        }
+    }
+
+    fn positions<F: bevy::ecs::query::ReadOnlyWorldQuery>(app: &mut App) -> Vec<Vec3> {
+        let mut positions: Vec<Vec3> = app
+            .world
+            .query_filtered::<&Transform, F>()
+            .iter(&app.world)
+            .map(|transform| transform.translation)
+            .collect();
+        positions.sort_by(|a, b| a.to_array().partial_cmp(&b.to_array()).unwrap());
+        positions
+    }
+
+    fn next_random(app: &mut App) -> u64 {
+        app.world.resource::<GameRng>().clone().next_u64()
+    }
+
+    #[test]
+    fn restored_run_plays_out_like_the_original() {
+        let active_profile = ActiveProfile {
+            name: "save_determinism_test".to_string(),
+        };
+
+        let mut original = headless_app(&active_profile);
+        start_game(&mut original);
+        seed_run(&mut original);
+        hold_keys(&mut original, [KeyCode::L, KeyCode::K]);
+        run(&mut original, TICKS_BEFORE_SAVE, TICK);
+
+        let mut save = IntoSystem::into_system(save_run);
+        save.initialize(&mut original.world);
+        save.run((), &mut original.world);
+        save.apply_deferred(&mut original.world);
+        let save_game = SaveGame::load(&active_profile).expect("the run was saved");
+        fs::remove_dir_all(active_profile.path("")).unwrap();
+
+        let mut restored = headless_app(&active_profile);
+        restored.insert_resource(ResumeRun(save_game));
+        start_game(&mut restored);
+
+        for app in [&mut original, &mut restored] {
+            hold_keys(app, [KeyCode::H, KeyCode::J]);
+            run(app, TICKS_AFTER_SAVE, TICK);
+        }
+
+        assert_eq!(
+            positions::<With<Player>>(&mut original),
+            positions::<With<Player>>(&mut restored)
+        );
+        assert_eq!(
+            positions::<With<Enemy>>(&mut original),
+            positions::<With<Enemy>>(&mut restored)
+        );
+        assert_eq!(
+            positions::<With<Star>>(&mut original),
+            positions::<With<Star>>(&mut restored)
+        );
+        assert_eq!(
+            original.world.resource::<Score>().value,
+            restored.world.resource::<Score>().value
+        );
+        assert_eq!(next_random(&mut original), next_random(&mut restored));
+    }
+}
@@ -0,0 +1,28 @@
+use bevy::prelude::Component;
+
+use super::resources::BindingAction;
+
+#[derive(Component)]
+pub struct SettingsMenu;
+
+#[derive(Component)]
+pub struct ControlSchemeText;
+
+#[derive(Component)]
+pub struct ReducedMotionText;
+
+#[derive(Component)]
+pub struct ThemeText;
+
+#[derive(Component)]
+pub struct LanguageText;
+
+#[derive(Component)]
+pub struct KeyBindingsMenu;
+
+/// The label of a binding's button on the key bindings screen.
+#[derive(Component)]
+pub struct BindingText {
+    pub player: usize,
+    pub action: BindingAction,
+}
@@ -0,0 +1,49 @@
+use bevy::{input::InputSystem, prelude::*, ui::UiSystem};
+use systems::interactions::*;
+use systems::layout::*;
+
+use crate::AppState;
+
+use self::resources::{ControlSettings, KeyBindings, Rebinding};
+
+mod components;
+pub mod resources;
+mod systems;
+
+const SETTINGS_BUTTON_WIDTH: f32 = 420.0;
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<KeyBindings>()
+            .init_resource::<ControlSettings>()
+            .init_resource::<Rebinding>()
+            .add_systems(OnEnter(AppState::Settings), spawn_settings_menu)
+            .add_systems(
+                Update,
+                (
+                    handle_settings_actions,
+                    update_settings_labels,
+                    update_language_label,
+                )
+                    .run_if(in_state(AppState::Settings)),
+            )
+            .add_systems(OnExit(AppState::Settings), despawn_settings_menu)
+            .add_systems(OnEnter(AppState::KeyBindings), spawn_key_bindings_menu)
+            // Before the focus moves or presses anything with the key being bound.
+            .add_systems(
+                PreUpdate,
+                capture_rebinding
+                    .after(InputSystem)
+                    .before(UiSystem::Focus)
+                    .run_if(in_state(AppState::KeyBindings)),
+            )
+            .add_systems(
+                Update,
+                (handle_key_bindings_actions, update_binding_labels)
+                    .run_if(in_state(AppState::KeyBindings)),
+            )
+            .add_systems(OnExit(AppState::KeyBindings), despawn_key_bindings_menu);
+    }
+}
@@ -0,0 +1,223 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::game::player::MAX_PLAYERS;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerBindings {
+    pub up: KeyCode,
+    pub down: KeyCode,
+    pub left: KeyCode,
+    pub right: KeyCode,
+    pub dash: KeyCode,
+}
+
+impl PlayerBindings {
+    /// Normalized direction of the movement keys currently held.
+    pub fn direction(&self, keyboard_input: &Input<KeyCode>) -> Vec2 {
+        let mut direction = Vec2::ZERO;
+
+        if keyboard_input.pressed(self.left) {
+            direction += Vec2::new(-1.0, 0.0);
+        }
+        if keyboard_input.pressed(self.right) {
+            direction += Vec2::new(1.0, 0.0);
+        }
+        if keyboard_input.pressed(self.up) {
+            direction += Vec2::new(0.0, 1.0);
+        }
+        if keyboard_input.pressed(self.down) {
+            direction += Vec2::new(0.0, -1.0);
+        }
+
+        direction.normalize_or_zero()
+    }
+
+    pub fn key(&self, action: BindingAction) -> KeyCode {
+        match action {
+            BindingAction::Up => self.up,
+            BindingAction::Down => self.down,
+            BindingAction::Left => self.left,
+            BindingAction::Right => self.right,
+            BindingAction::Dash => self.dash,
+        }
+    }
+
+    fn key_mut(&mut self, action: BindingAction) -> &mut KeyCode {
+        match action {
+            BindingAction::Up => &mut self.up,
+            BindingAction::Down => &mut self.down,
+            BindingAction::Left => &mut self.left,
+            BindingAction::Right => &mut self.right,
+            BindingAction::Dash => &mut self.dash,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BindingAction {
+    Up,
+    Down,
+    Left,
+    Right,
+    Dash,
+}
+
+impl BindingAction {
+    pub const ALL: [BindingAction; 5] = [
+        BindingAction::Up,
+        BindingAction::Down,
+        BindingAction::Left,
+        BindingAction::Right,
+        BindingAction::Dash,
+    ];
+
+    /// String table key.
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            BindingAction::Up => "key_bindings.up",
+            BindingAction::Down => "key_bindings.down",
+            BindingAction::Left => "key_bindings.left",
+            BindingAction::Right => "key_bindings.right",
+            BindingAction::Dash => "key_bindings.dash",
+        }
+    }
+}
+
+/// Keyboard bindings for each local player, indexed by `Player::id`.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct KeyBindings {
+    pub players: [PlayerBindings; MAX_PLAYERS],
+}
+
+impl KeyBindings {
+    pub fn player(&self, id: usize) -> &PlayerBindings {
+        &self.players[id]
+    }
+
+    /// Binds `key` to a player's action. Whichever binding had `key` before takes the old key,
+    /// so no key ends up doing two things.
+    pub fn rebind(&mut self, id: usize, action: BindingAction, key: KeyCode) {
+        let old_key = self.players[id].key(action);
+        for bindings in self.players.iter_mut() {
+            for other_action in BindingAction::ALL {
+                let other_key = bindings.key_mut(other_action);
+                if *other_key == key {
+                    *other_key = old_key;
+                }
+            }
+        }
+        *self.players[id].key_mut(action) = key;
+    }
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        KeyBindings {
+            players: [
+                PlayerBindings {
+                    up: KeyCode::K,
+                    down: KeyCode::J,
+                    left: KeyCode::H,
+                    right: KeyCode::L,
+                    dash: KeyCode::ShiftLeft,
+                },
+                PlayerBindings {
+                    up: KeyCode::Up,
+                    down: KeyCode::Down,
+                    left: KeyCode::Left,
+                    right: KeyCode::Right,
+                    dash: KeyCode::ShiftRight,
+                },
+            ],
+        }
+    }
+}
+
+/// The binding on the key bindings screen waiting for a key press, if any.
+#[derive(Resource, Debug, Default)]
+pub struct Rebinding {
+    pub target: Option<(usize, BindingAction)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ControlScheme {
+    /// Full speed instantly, stops dead.
+    #[default]
+    Direct,
+    /// Accelerates up to a top speed and glides to a stop.
+    Momentum,
+    /// Follows the mouse cursor or a touch point.
+    Pointer,
+}
+
+impl ControlScheme {
+    pub fn next(&self) -> ControlScheme {
+        match self {
+            ControlScheme::Direct => ControlScheme::Momentum,
+            ControlScheme::Momentum => ControlScheme::Pointer,
+            ControlScheme::Pointer => ControlScheme::Direct,
+        }
+    }
+
+    /// String table key.
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            ControlScheme::Direct => "control_scheme.direct",
+            ControlScheme::Momentum => "control_scheme.momentum",
+            ControlScheme::Pointer => "control_scheme.pointer",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MomentumSettings {
+    pub acceleration: f32,
+    /// Fraction of velocity lost per second when there is no input.
+    pub friction: f32,
+    pub max_speed: f32,
+    /// Fraction of velocity kept, reversed, when hitting a wall; zero stops dead.
+    pub wall_restitution: f32,
+}
+
+impl Default for MomentumSettings {
+    fn default() -> Self {
+        MomentumSettings {
+            acceleration: 2400.0,
+            friction: 4.0,
+            max_speed: 550.0,
+            wall_restitution: 0.5,
+        }
+    }
+}
+
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ControlSettings {
+    pub scheme: ControlScheme,
+    pub momentum: MomentumSettings,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rebinding_a_taken_key_swaps_it() {
+        let mut key_bindings = KeyBindings::default();
+
+        key_bindings.rebind(0, BindingAction::Up, KeyCode::Down);
+
+        assert_eq!(key_bindings.player(0).up, KeyCode::Down);
+        assert_eq!(key_bindings.player(1).down, KeyCode::K);
+    }
+
+    #[test]
+    fn rebinding_a_free_key_leaves_the_others() {
+        let mut key_bindings = KeyBindings::default();
+
+        key_bindings.rebind(1, BindingAction::Dash, KeyCode::Space);
+
+        assert_eq!(key_bindings.player(1).dash, KeyCode::Space);
+        assert_eq!(key_bindings.player(0).dash, KeyCode::ShiftLeft);
+    }
+}
@@ -0,0 +1,76 @@
+use bevy::prelude::*;
+
+use crate::{
+    camera::resources::CameraSettings,
+    events::MenuButtonPressed,
+    localization::resources::LanguageSettings,
+    settings::resources::{ControlSettings, KeyBindings, Rebinding},
+    theme::resources::ThemeSettings,
+    widgets::components::MenuAction,
+};
+
+pub fn handle_settings_actions(
+    mut menu_button_event_reader: EventReader<MenuButtonPressed>,
+    mut control_settings: ResMut<ControlSettings>,
+    mut camera_settings: ResMut<CameraSettings>,
+    mut theme_settings: ResMut<ThemeSettings>,
+    mut language_settings: ResMut<LanguageSettings>,
+) {
+    for event in menu_button_event_reader.iter() {
+        match event.action {
+            MenuAction::CycleControlScheme => {
+                control_settings.scheme = control_settings.scheme.next();
+            }
+            MenuAction::ToggleReducedMotion => {
+                camera_settings.reduced_motion = !camera_settings.reduced_motion;
+            }
+            MenuAction::CycleTheme => {
+                theme_settings.theme = theme_settings.theme.next();
+            }
+            MenuAction::CycleLanguage => {
+                language_settings.language = language_settings.language.next();
+            }
+            _ => {}
+        }
+    }
+}
+
+pub fn handle_key_bindings_actions(
+    mut menu_button_event_reader: EventReader<MenuButtonPressed>,
+    mut key_bindings: ResMut<KeyBindings>,
+    mut rebinding: ResMut<Rebinding>,
+) {
+    for event in menu_button_event_reader.iter() {
+        match event.action {
+            MenuAction::Rebind(player, action) => {
+                rebinding.target = Some((player, action));
+            }
+            MenuAction::ResetKeyBindings => {
+                *key_bindings = KeyBindings::default();
+                rebinding.target = None;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Binds the next key pressed to the waiting binding; Escape cancels. Runs before the focus
+/// and the global shortcuts get to see the key, and swallows it so they don't act on it too.
+pub fn capture_rebinding(
+    mut keyboard_input: ResMut<Input<KeyCode>>,
+    mut key_bindings: ResMut<KeyBindings>,
+    mut rebinding: ResMut<Rebinding>,
+) {
+    let Some((player, action)) = rebinding.target else {
+        return;
+    };
+    let Some(&key) = keyboard_input.get_just_pressed().next() else {
+        return;
+    };
+
+    keyboard_input.clear_just_pressed(key);
+    rebinding.target = None;
+    if key != KeyCode::Escape {
+        key_bindings.rebind(player, action, key);
+    }
+}
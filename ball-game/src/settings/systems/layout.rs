@@ -0,0 +1,347 @@
+use bevy::prelude::*;
+
+use crate::{
+    camera::resources::CameraSettings,
+    localization::{
+        components::LocalizedText,
+        resources::{LanguageSettings, LocalizedString},
+    },
+    main_menu::styles::{get_body_text_style, get_main_menu_style, get_title_text_style},
+    settings::{
+        components::*,
+        resources::{BindingAction, ControlSettings, KeyBindings, Rebinding},
+        SETTINGS_BUTTON_WIDTH,
+    },
+    theme::{
+        components::{MenuBackground, ThemedText},
+        resources::{ThemeSettings, UiTheme},
+    },
+    widgets::{builder::MenuButtonBuilder, components::MenuAction},
+    AppState,
+};
+
+type ControlSchemeTextOnly = (
+    With<ControlSchemeText>,
+    Without<ReducedMotionText>,
+    Without<ThemeText>,
+);
+
+pub fn control_scheme_label(control_settings: &ControlSettings) -> LocalizedString {
+    LocalizedString::new("settings.controls").with_localized_arg(
+        "scheme",
+        LocalizedString::new(control_settings.scheme.label_key()),
+    )
+}
+
+pub fn reduced_motion_label(camera_settings: &CameraSettings) -> LocalizedString {
+    let state = if camera_settings.reduced_motion {
+        "settings.on"
+    } else {
+        "settings.off"
+    };
+    LocalizedString::new("settings.reduced_motion")
+        .with_localized_arg("state", LocalizedString::new(state))
+}
+
+pub fn theme_label(theme_settings: &ThemeSettings) -> LocalizedString {
+    LocalizedString::new("settings.theme").with_localized_arg(
+        "theme",
+        LocalizedString::new(theme_settings.theme.label_key()),
+    )
+}
+
+pub fn language_label(language_settings: &LanguageSettings) -> LocalizedString {
+    LocalizedString::new("settings.language")
+        .with_arg("language", language_settings.language.label())
+}
+
+pub fn binding_label(
+    key_bindings: &KeyBindings,
+    rebinding: &Rebinding,
+    player: usize,
+    action: BindingAction,
+) -> LocalizedString {
+    let action_label = LocalizedString::new(action.label_key());
+    if rebinding.target == Some((player, action)) {
+        LocalizedString::new("key_bindings.waiting").with_localized_arg("action", action_label)
+    } else {
+        LocalizedString::new("key_bindings.binding")
+            .with_localized_arg("action", action_label)
+            .with_arg(
+                "key",
+                format!("{:?}", key_bindings.player(player).key(action)),
+            )
+    }
+}
+
+pub fn spawn_settings_menu(
+    mut commands: Commands,
+    ui_theme: Res<UiTheme>,
+    control_settings: Res<ControlSettings>,
+    camera_settings: Res<CameraSettings>,
+    theme_settings: Res<ThemeSettings>,
+    language_settings: Res<LanguageSettings>,
+) {
+    build_settings_menu(
+        &mut commands,
+        &ui_theme,
+        &control_settings,
+        &camera_settings,
+        &theme_settings,
+        &language_settings,
+    );
+}
+
+pub fn despawn_settings_menu(
+    mut commands: Commands,
+    settings_menu_query: Query<Entity, With<SettingsMenu>>,
+) {
+    if let Ok(settings_menu_entity) = settings_menu_query.get_single() {
+        commands.entity(settings_menu_entity).despawn_recursive();
+    }
+}
+
+pub fn build_settings_menu(
+    commands: &mut Commands,
+    ui_theme: &UiTheme,
+    control_settings: &ControlSettings,
+    camera_settings: &CameraSettings,
+    theme_settings: &ThemeSettings,
+    language_settings: &LanguageSettings,
+) -> Entity {
+    let settings_menu_entity = commands
+        .spawn((
+            NodeBundle {
+                style: get_main_menu_style(ui_theme),
+                background_color: ui_theme.palette.background.into(),
+                ..default()
+            },
+            MenuBackground {},
+            SettingsMenu {},
+        ))
+        .with_children(|parent| {
+            // === Title ===
+            parent.spawn((
+                TextBundle::from_section("", get_title_text_style(ui_theme)),
+                LocalizedText::new(LocalizedString::new("settings.title")),
+                ThemedText::Title,
+            ));
+            // === Control Scheme Button ===
+            MenuButtonBuilder::new(
+                control_scheme_label(control_settings),
+                MenuAction::CycleControlScheme,
+                0,
+            )
+            .width(SETTINGS_BUTTON_WIDTH)
+            .spawn_with_text(parent, ui_theme, ControlSchemeText {});
+            // === Reduced Motion Button ===
+            MenuButtonBuilder::new(
+                reduced_motion_label(camera_settings),
+                MenuAction::ToggleReducedMotion,
+                1,
+            )
+            .width(SETTINGS_BUTTON_WIDTH)
+            .spawn_with_text(parent, ui_theme, ReducedMotionText {});
+            // === Theme Button ===
+            MenuButtonBuilder::new(theme_label(theme_settings), MenuAction::CycleTheme, 2)
+                .width(SETTINGS_BUTTON_WIDTH)
+                .spawn_with_text(parent, ui_theme, ThemeText {});
+            // === Language Button ===
+            MenuButtonBuilder::new(
+                language_label(language_settings),
+                MenuAction::CycleLanguage,
+                3,
+            )
+            .width(SETTINGS_BUTTON_WIDTH)
+            .spawn_with_text(parent, ui_theme, LanguageText {});
+            // === Key Bindings Button ===
+            MenuButtonBuilder::new(
+                LocalizedString::new("settings.key_bindings"),
+                MenuAction::GoTo(AppState::KeyBindings),
+                4,
+            )
+            .width(SETTINGS_BUTTON_WIDTH)
+            .spawn(parent, ui_theme);
+            // === Back Button ===
+            MenuButtonBuilder::new(
+                LocalizedString::new("common.back"),
+                MenuAction::GoTo(AppState::MainMenu),
+                5,
+            )
+            .spawn(parent, ui_theme);
+        })
+        .id();
+
+    settings_menu_entity
+}
+
+pub fn update_settings_labels(
+    mut control_scheme_text_query: Query<&mut LocalizedText, ControlSchemeTextOnly>,
+    mut reduced_motion_text_query: Query<
+        &mut LocalizedText,
+        (With<ReducedMotionText>, Without<ThemeText>),
+    >,
+    mut theme_text_query: Query<&mut LocalizedText, With<ThemeText>>,
+    control_settings: Res<ControlSettings>,
+    camera_settings: Res<CameraSettings>,
+    theme_settings: Res<ThemeSettings>,
+) {
+    if control_settings.is_changed() {
+        if let Ok(mut text) = control_scheme_text_query.get_single_mut() {
+            text.sections[0] = control_scheme_label(&control_settings);
+        }
+    }
+    if camera_settings.is_changed() {
+        if let Ok(mut text) = reduced_motion_text_query.get_single_mut() {
+            text.sections[0] = reduced_motion_label(&camera_settings);
+        }
+    }
+    if theme_settings.is_changed() {
+        if let Ok(mut text) = theme_text_query.get_single_mut() {
+            text.sections[0] = theme_label(&theme_settings);
+        }
+    }
+}
+
+pub fn update_language_label(
+    mut text_query: Query<&mut LocalizedText, With<LanguageText>>,
+    language_settings: Res<LanguageSettings>,
+) {
+    if language_settings.is_changed() {
+        if let Ok(mut text) = text_query.get_single_mut() {
+            text.sections[0] = language_label(&language_settings);
+        }
+    }
+}
+
+pub fn spawn_key_bindings_menu(
+    mut commands: Commands,
+    ui_theme: Res<UiTheme>,
+    key_bindings: Res<KeyBindings>,
+    rebinding: Res<Rebinding>,
+) {
+    build_key_bindings_menu(&mut commands, &ui_theme, &key_bindings, &rebinding);
+}
+
+pub fn despawn_key_bindings_menu(
+    mut commands: Commands,
+    key_bindings_menu_query: Query<Entity, With<KeyBindingsMenu>>,
+    mut rebinding: ResMut<Rebinding>,
+) {
+    if let Ok(key_bindings_menu_entity) = key_bindings_menu_query.get_single() {
+        commands
+            .entity(key_bindings_menu_entity)
+            .despawn_recursive();
+    }
+    rebinding.target = None;
+}
+
+pub fn build_key_bindings_menu(
+    commands: &mut Commands,
+    ui_theme: &UiTheme,
+    key_bindings: &KeyBindings,
+    rebinding: &Rebinding,
+) -> Entity {
+    let key_bindings_menu_entity = commands
+        .spawn((
+            NodeBundle {
+                style: get_main_menu_style(ui_theme),
+                background_color: ui_theme.palette.background.into(),
+                ..default()
+            },
+            MenuBackground {},
+            KeyBindingsMenu {},
+        ))
+        .with_children(|parent| {
+            // === Title ===
+            parent.spawn((
+                TextBundle::from_section("", get_title_text_style(ui_theme)),
+                LocalizedText::new(LocalizedString::new("key_bindings.title")),
+                ThemedText::Title,
+            ));
+            // === One Column Per Player ===
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(ui_theme.spacing.gap),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for (player, _) in key_bindings.players.iter().enumerate() {
+                        parent
+                            .spawn(NodeBundle {
+                                style: Style {
+                                    flex_direction: FlexDirection::Column,
+                                    align_items: AlignItems::Center,
+                                    row_gap: Val::Px(ui_theme.spacing.gap),
+                                    ..default()
+                                },
+                                ..default()
+                            })
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    TextBundle::from_section("", get_body_text_style(ui_theme)),
+                                    LocalizedText::new(
+                                        LocalizedString::new("key_bindings.player")
+                                            .with_arg("player", (player + 1).to_string()),
+                                    ),
+                                    ThemedText::Body,
+                                ));
+                                for (index, action) in BindingAction::ALL.into_iter().enumerate() {
+                                    let order = (player * BindingAction::ALL.len() + index) as u32;
+                                    MenuButtonBuilder::new(
+                                        binding_label(key_bindings, rebinding, player, action),
+                                        MenuAction::Rebind(player, action),
+                                        order,
+                                    )
+                                    .width(SETTINGS_BUTTON_WIDTH)
+                                    .spawn_with_text(
+                                        parent,
+                                        ui_theme,
+                                        BindingText { player, action },
+                                    );
+                                }
+                            });
+                    }
+                });
+            let order = (key_bindings.players.len() * BindingAction::ALL.len()) as u32;
+            // === Reset Button ===
+            MenuButtonBuilder::new(
+                LocalizedString::new("key_bindings.reset"),
+                MenuAction::ResetKeyBindings,
+                order,
+            )
+            .width(SETTINGS_BUTTON_WIDTH)
+            .spawn(parent, ui_theme);
+            // === Back Button ===
+            MenuButtonBuilder::new(
+                LocalizedString::new("common.back"),
+                MenuAction::GoTo(AppState::Settings),
+                order + 1,
+            )
+            .spawn(parent, ui_theme);
+        })
+        .id();
+
+    key_bindings_menu_entity
+}
+
+pub fn update_binding_labels(
+    mut text_query: Query<(&mut LocalizedText, &BindingText)>,
+    key_bindings: Res<KeyBindings>,
+    rebinding: Res<Rebinding>,
+) {
+    if key_bindings.is_changed() || rebinding.is_changed() {
+        for (mut text, binding_text) in text_query.iter_mut() {
+            text.sections[0] = binding_label(
+                &key_bindings,
+                &rebinding,
+                binding_text.player,
+                binding_text.action,
+            );
+        }
+    }
+}
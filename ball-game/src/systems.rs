@@ -1,23 +1,26 @@
-use crate::{events::*, game::SimulationState, AppState};
-use bevy::{app::AppExit, prelude::*, window::PrimaryWindow};
-
-pub fn spawn_camera(mut commands: Commands, window_query: Query<&Window, With<PrimaryWindow>>) {
-    let window = window_query.get_single().unwrap();
-
-    commands.spawn(Camera2dBundle {
-        transform: Transform::from_xyz(window.width() / 2.0, window.height() / 2.0, 1.0),
-        ..default()
-    });
-}
+use crate::{
+    events::*,
+    game::SimulationState,
+    localization::{console::PrintLocalized, resources::LocalizedString},
+    AppState,
+};
+use bevy::{app::AppExit, prelude::*};
 
 pub fn transition_to_game_state(
+    mut commands: Commands,
     keyboard_input: Res<Input<KeyCode>>,
     app_state: Res<State<AppState>>,
     mut next_app_state: ResMut<NextState<AppState>>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::G) && app_state.get() != &AppState::InGame {
+    // The editor has its own key for test playing, and G can be part of a profile name.
+    if keyboard_input.just_pressed(KeyCode::G)
+        && app_state.get() != &AppState::InGame
+        && app_state.get() != &AppState::Loading
+        && app_state.get() != &AppState::ProfileSelect
+        && app_state.get() != &AppState::Editor
+    {
         next_app_state.set(AppState::InGame);
-        println!("Entered AppState::InGame");
+        commands.add(PrintLocalized(LocalizedString::new("console.entered_game")));
     }
 }
 
@@ -27,7 +30,12 @@ pub fn transition_to_main_menu_state(
     mut next_app_state: ResMut<NextState<AppState>>,
     mut next_simulation_state: ResMut<NextState<SimulationState>>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::M) && app_state.get() != &AppState::MainMenu {
+    // There's no main menu without a profile.
+    if keyboard_input.just_pressed(KeyCode::M)
+        && app_state.get() != &AppState::MainMenu
+        && app_state.get() != &AppState::Loading
+        && app_state.get() != &AppState::ProfileSelect
+    {
         next_app_state.set(AppState::MainMenu);
         next_simulation_state.set(SimulationState::Paused);
     }
@@ -52,11 +60,19 @@ pub fn exit_game(
 }
 
 pub fn handle_game_over(
+    mut commands: Commands,
     mut game_over_event_reader: EventReader<GameOver>,
     mut app_state_next_state: ResMut<NextState<AppState>>,
 ) {
     for event in game_over_event_reader.iter() {
-        println!("Your final score is: {}", event.score);
+        if let Some(winner) = event.winner {
+            commands.add(PrintLocalized(
+                LocalizedString::new("console.player_wins").with_arg("player", winner + 1),
+            ));
+        }
+        commands.add(PrintLocalized(
+            LocalizedString::new("console.final_score").with_arg("score", event.score),
+        ));
         app_state_next_state.set(AppState::GameOver);
     }
 }
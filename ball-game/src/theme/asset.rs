@@ -0,0 +1,68 @@
+use bevy::{
+    asset::{AssetLoader, LoadContext, LoadedAsset},
+    prelude::*,
+    reflect::{TypePath, TypeUuid},
+    utils::BoxedFuture,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Palette {
+    /// Behind the menus.
+    pub background: Color,
+    pub button: Color,
+    pub button_hovered: Color,
+    pub button_pressed: Color,
+    pub text: Color,
+    /// Text for things that aren't available yet, like locked achievements.
+    pub text_muted: Color,
+    /// Popups drawn over the game, like achievement toasts.
+    pub panel: Color,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FontSizes {
+    pub title: f32,
+    pub button: f32,
+    pub body: f32,
+    pub detail: f32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Spacing {
+    /// Between the items of a menu.
+    pub gap: f32,
+    pub button_height: f32,
+}
+
+/// Look of the menus, read from `assets/themes/*.theme.ron`.
+#[derive(Debug, Clone, Deserialize, TypeUuid, TypePath)]
+#[uuid = "b1d7e0c2-5a43-4f6e-8c1d-2e9a7f3b6d54"]
+pub struct Theme {
+    pub palette: Palette,
+    /// Relative to `assets/`.
+    pub font: String,
+    pub font_sizes: FontSizes,
+    pub spacing: Spacing,
+}
+
+#[derive(Default)]
+pub struct ThemeLoader;
+
+impl AssetLoader for ThemeLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let theme = ron::de::from_bytes::<Theme>(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(theme));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["theme.ron"]
+    }
+}
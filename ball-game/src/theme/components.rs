@@ -0,0 +1,14 @@
+use bevy::prelude::Component;
+
+/// Text restyled when the theme changes, by what it's used for.
+#[derive(Component, Debug, Clone, Copy)]
+pub enum ThemedText {
+    Title,
+    Button,
+    Body,
+    Detail,
+}
+
+/// The full-screen node a menu is built in.
+#[derive(Component)]
+pub struct MenuBackground;
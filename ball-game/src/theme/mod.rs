@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+use crate::AppState;
+
+use self::{
+    resources::{ThemeSettings, UiTheme},
+    systems::*,
+};
+
+pub mod asset;
+pub mod components;
+pub mod resources;
+mod systems;
+
+/// Menu colors, fonts and spacing, from the theme picked in the settings.
+pub struct ThemePlugin;
+
+impl Plugin for ThemePlugin {
+    fn build(&self, app: &mut App) {
+        // The menus need a theme from the moment loading is done.
+        app.init_resource::<ThemeSettings>()
+            .add_systems(OnExit(AppState::Loading), apply_theme)
+            .add_systems(
+                Update,
+                (
+                    apply_theme.run_if(resource_changed::<ThemeSettings>()),
+                    restyle_ui.run_if(resource_exists_and_changed::<UiTheme>()),
+                )
+                    .run_if(not(in_state(AppState::Loading))),
+            );
+    }
+}
@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::asset::{FontSizes, Palette, Spacing, Theme};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ThemeName {
+    #[default]
+    Dark,
+    Light,
+    HighContrast,
+}
+
+impl ThemeName {
+    /// String table key.
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            ThemeName::Dark => "theme.dark",
+            ThemeName::Light => "theme.light",
+            ThemeName::HighContrast => "theme.high_contrast",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            ThemeName::Dark => ThemeName::Light,
+            ThemeName::Light => ThemeName::HighContrast,
+            ThemeName::HighContrast => ThemeName::Dark,
+        }
+    }
+}
+
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ThemeSettings {
+    pub theme: ThemeName,
+}
+
+/// The theme the menus are drawn with, swapped out whenever `ThemeSettings` changes.
+#[derive(Resource)]
+pub struct UiTheme {
+    pub palette: Palette,
+    pub font: Handle<Font>,
+    pub font_sizes: FontSizes,
+    pub spacing: Spacing,
+}
+
+impl UiTheme {
+    pub fn new(theme: &Theme, asset_server: &AssetServer) -> Self {
+        UiTheme {
+            palette: theme.palette.clone(),
+            font: asset_server.load(theme.font.as_str()),
+            font_sizes: theme.font_sizes.clone(),
+            spacing: theme.spacing.clone(),
+        }
+    }
+}
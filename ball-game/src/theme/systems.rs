@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+
+use super::{
+    asset::Theme,
+    components::{MenuBackground, ThemedText},
+    resources::{ThemeSettings, UiTheme},
+};
+use crate::{
+    loading::resources::GameAssets,
+    main_menu::styles::{get_main_menu_style, get_themed_text_style},
+    widgets::components::MenuButton,
+};
+
+type MenuBackgroundOnly = (With<MenuBackground>, Without<MenuButton>);
+
+pub fn apply_theme(
+    mut commands: Commands,
+    theme_settings: Res<ThemeSettings>,
+    game_assets: Res<GameAssets>,
+    themes: Res<Assets<Theme>>,
+    asset_server: Res<AssetServer>,
+) {
+    if let Some(theme) = themes.get(game_assets.theme(theme_settings.theme)) {
+        commands.insert_resource(UiTheme::new(theme, &asset_server));
+    }
+}
+
+/// Brings whatever is on screen in line with the new theme, so nothing has to be rebuilt.
+pub fn restyle_ui(
+    mut background_query: Query<(&mut Style, &mut BackgroundColor), MenuBackgroundOnly>,
+    mut button_query: Query<(&mut Style, &mut BackgroundColor), With<MenuButton>>,
+    mut text_query: Query<(&mut Text, &ThemedText)>,
+    ui_theme: Res<UiTheme>,
+) {
+    for (mut style, mut background_color) in background_query.iter_mut() {
+        *style = get_main_menu_style(&ui_theme);
+        *background_color = ui_theme.palette.background.into();
+    }
+    for (mut style, mut background_color) in button_query.iter_mut() {
+        style.height = Val::Px(ui_theme.spacing.button_height);
+        *background_color = ui_theme.palette.button.into();
+    }
+    for (mut text, themed_text) in text_query.iter_mut() {
+        for section in text.sections.iter_mut() {
+            section.style = get_themed_text_style(&ui_theme, *themed_text);
+        }
+    }
+}
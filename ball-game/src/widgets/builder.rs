@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+
+use crate::{
+    focus::components::Focusable,
+    localization::{components::LocalizedText, resources::LocalizedString},
+    main_menu::styles::{get_button_style, get_button_text_style},
+    theme::{components::ThemedText, resources::UiTheme},
+    widgets::components::{MenuAction, MenuButton},
+};
+
+/// Builds a `MenuButton` with a single line of text, reachable with the keyboard at `order`.
+pub struct MenuButtonBuilder {
+    label: LocalizedString,
+    action: MenuAction,
+    order: u32,
+    width: Option<f32>,
+}
+
+impl MenuButtonBuilder {
+    pub fn new(label: LocalizedString, action: MenuAction, order: u32) -> Self {
+        MenuButtonBuilder {
+            label,
+            action,
+            order,
+            width: None,
+        }
+    }
+
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn spawn(self, parent: &mut ChildBuilder, ui_theme: &UiTheme) -> Entity {
+        self.spawn_with_text(parent, ui_theme, ())
+    }
+
+    /// Adds `text_bundle` to the button's text, for labels that change while on screen.
+    pub fn spawn_with_text(
+        self,
+        parent: &mut ChildBuilder,
+        ui_theme: &UiTheme,
+        text_bundle: impl Bundle,
+    ) -> Entity {
+        let mut style = get_button_style(ui_theme);
+        if let Some(width) = self.width {
+            style.width = Val::Px(width);
+        }
+
+        parent
+            .spawn((
+                ButtonBundle {
+                    style,
+                    background_color: ui_theme.palette.button.into(),
+                    ..default()
+                },
+                MenuButton {
+                    action: self.action,
+                },
+                Focusable { order: self.order },
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    TextBundle {
+                        text: Text {
+                            sections: vec![TextSection::new("", get_button_text_style(ui_theme))],
+                            alignment: TextAlignment::Center,
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    ThemedText::Button,
+                    LocalizedText::new(self.label),
+                    text_bundle,
+                ));
+            })
+            .id()
+    }
+}
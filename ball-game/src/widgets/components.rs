@@ -0,0 +1,33 @@
+use bevy::prelude::Component;
+
+use crate::{game::player::resources::PlayerMode, settings::resources::BindingAction, AppState};
+
+/// A menu button; pressing it sends a `MenuButtonPressed` with its `action`.
+#[derive(Component)]
+pub struct MenuButton {
+    pub action: MenuAction,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MenuAction {
+    /// Switches to another screen.
+    GoTo(AppState),
+    Quit,
+    Continue,
+    CycleGameMode,
+    Play(PlayerMode),
+    Host,
+    CycleControlScheme,
+    ToggleReducedMotion,
+    CycleTheme,
+    CycleLanguage,
+    /// Waits for the next key press to bind to a player's action.
+    Rebind(usize, BindingAction),
+    ResetKeyBindings,
+    /// Picks the level for the next run; `None` for the open arena.
+    SelectLevel(Option<usize>),
+    /// Starts a campaign stage, by index into `STAGES`.
+    StartStage(usize),
+    SelectProfile(String),
+    CreateProfile,
+}
@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+
+use crate::{events::MenuButtonPressed, theme::resources::UiTheme};
+
+use self::systems::*;
+
+pub mod builder;
+pub mod components;
+mod systems;
+
+/// Menu buttons for every screen: hover and press colors, plus a `MenuButtonPressed` event
+/// the screens act on.
+pub struct WidgetsPlugin;
+
+impl Plugin for WidgetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<MenuButtonPressed>().add_systems(
+            Update,
+            (
+                color_buttons,
+                (interact_with_menu_buttons, handle_common_actions).chain(),
+            )
+                .run_if(resource_exists::<UiTheme>()),
+        );
+    }
+}
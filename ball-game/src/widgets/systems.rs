@@ -0,0 +1,52 @@
+use bevy::{app::AppExit, prelude::*};
+
+use crate::{
+    events::MenuButtonPressed,
+    theme::resources::UiTheme,
+    widgets::components::{MenuAction, MenuButton},
+    AppState,
+};
+
+type ChangedButton = (Changed<Interaction>, With<Button>);
+
+/// Hover and press colors for every button, menu or otherwise.
+pub fn color_buttons(
+    mut button_query: Query<(&Interaction, &mut BackgroundColor), ChangedButton>,
+    ui_theme: Res<UiTheme>,
+) {
+    for (interaction, mut background_color) in button_query.iter_mut() {
+        *background_color = match *interaction {
+            Interaction::Pressed => ui_theme.palette.button_pressed.into(),
+            Interaction::Hovered => ui_theme.palette.button_hovered.into(),
+            Interaction::None => ui_theme.palette.button.into(),
+        };
+    }
+}
+
+pub fn interact_with_menu_buttons(
+    button_query: Query<(&Interaction, &MenuButton), Changed<Interaction>>,
+    mut menu_button_event_writer: EventWriter<MenuButtonPressed>,
+) {
+    for (interaction, menu_button) in button_query.iter() {
+        if *interaction == Interaction::Pressed {
+            menu_button_event_writer.send(MenuButtonPressed {
+                action: menu_button.action.clone(),
+            });
+        }
+    }
+}
+
+/// Handles the actions that mean the same on every screen; the rest are up to the screens.
+pub fn handle_common_actions(
+    mut menu_button_event_reader: EventReader<MenuButtonPressed>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut app_exit_event_writer: EventWriter<AppExit>,
+) {
+    for event in menu_button_event_reader.iter() {
+        match event.action {
+            MenuAction::GoTo(app_state) => next_app_state.set(app_state),
+            MenuAction::Quit => app_exit_event_writer.send(AppExit),
+            _ => {}
+        }
+    }
+}